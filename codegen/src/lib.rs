@@ -172,6 +172,35 @@ use synthez_core::codegen;
 /// reporting). You may use the [`Spanning`] wrapper in case it doesn't
 /// implement the latest.
 ///
+/// # Schema
+///
+/// The derived [`ParseAttrs::schema()`] describes all the parsed arguments
+/// (their names, kinds, deduplication strategies, required-ness and doc
+/// comments), allowing to generate reference documentation out of it.
+///
+/// Its [`Schema::help()`] summary is also attached to the errors about unknown
+/// arguments, emitted by the derived [`Parse`] implementation.
+///
+/// ```rust
+/// # use synthez::{ParseAttrs, Required};
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// struct MyAttrs {
+///     /// Name to use instead.
+///     #[parse(value, alias = name)]
+///     rename: Required<syn::LitStr>,
+/// }
+///
+/// # fn main() {
+/// let schema = MyAttrs::schema();
+///
+/// assert_eq!(schema.args[0].name, "rename");
+/// assert_eq!(schema.args[0].aliases, ["name"]);
+/// assert_eq!(schema.args[0].doc, Some("Name to use instead."));
+/// assert!(schema.args[0].required);
+/// # }
+/// ```
+///
 /// # Arguments
 ///
 /// ## `ident`, `value`, `map` or `nested` (mandatory)
@@ -471,13 +500,318 @@ use synthez_core::codegen;
 /// # }
 /// ```
 ///
+/// ## `with = <func>` (optional)
+///
+/// Allows to specify a function for parsing the field value instead of its
+/// [`Parse`] implementation. The signature of the function should be the
+/// following:
+/// ```rust,ignore
+/// fn(ParseStream<'_>) -> syn::Result<ValueType>
+/// ```
+///
+/// Works with every kind of parsing:
+/// - `ident` and `value`: parses a single value;
+/// - `map`: parses a whole `(KeyType, ValueType)` pair, following the argument
+///   name;
+/// - `nested`: parses contents of the parentheses.
+///
+/// ```rust
+/// # use syn::{parse::ParseStream, parse_quote};
+/// # use synthez::ParseAttrs;
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// struct MyAttrs {
+///     #[parse(value, with = lit_str_as_path)]
+///     module: Option<syn::Path>,
+/// }
+///
+/// fn lit_str_as_path(input: ParseStream<'_>) -> syn::Result<syn::Path> {
+///     input.parse::<syn::LitStr>()?.parse()
+/// }
+///
+/// # fn main() {
+/// let input: syn::DeriveInput = parse_quote! {
+///     #[my_attr(module = "foo::bar")]
+///     struct Dummy;
+/// };
+/// let my_attrs = MyAttrs::parse_attrs("my_attr", &input);
+///
+/// # assert!(my_attrs.is_ok());
+/// # let my_attrs = my_attrs.unwrap();
+/// assert_eq!(my_attrs.module, Some(parse_quote!(foo::bar)));
+/// # }
+/// ```
+///
+/// ## `value(from_str)` (optional)
+///
+/// Allows the `value` to be specified either directly or inside a string
+/// literal, so both `module = my::module` and `module = "my::module"` are
+/// accepted. The contents of a string literal are parsed with the literal's
+/// span, so errors point to it. May be combined with `spaced` as
+/// `value(spaced, from_str)`, and with a custom `with` function, which is then
+/// applied to the string literal contents.
+///
+/// Cannot be used for string values (like [`syn::LitStr`] or [`String`])
+/// without a custom `with` function, as they're string literals already.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::ParseAttrs;
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// struct MyAttrs {
+///     #[parse(value(from_str))]
+///     module: Vec<syn::Path>,
+/// }
+///
+/// # fn main() {
+/// let input: syn::DeriveInput = parse_quote! {
+///     #[my_attr(module = foo::bar, module = "baz::qux")]
+///     struct Dummy;
+/// };
+/// let my_attrs = MyAttrs::parse_attrs("my_attr", &input);
+///
+/// # assert!(my_attrs.is_ok());
+/// # let my_attrs = my_attrs.unwrap();
+/// assert_eq!(
+///     my_attrs.module,
+///     [parse_quote!(foo::bar), parse_quote!(baz::qux)],
+/// );
+/// # }
+/// ```
+///
+/// ## `inherit`, `inherit = <func>` (optional)
+///
+/// Makes the field inherit its value from the parent [`ParseAttrs`] declared
+/// with the [`inherit = <type>`](#inherit--type-optional) struct argument.
+///
+/// Bare `inherit` clones the value of the parent's field with the same name,
+/// if no value has been parsed for this field. Otherwise, the provided function
+/// is invoked with the following signature:
+/// ```rust,ignore
+/// fn(&mut FieldType, &ParentType) -> syn::Result<()>
+/// ```
+///
+/// Similarly to the `fallback` argument, it's the function's responsibility to
+/// determine whether inheriting is actually required, so
+/// [`field::if_empty()`] may be used here too.
+///
+/// See the [`inherit = <type>`](#inherit--type-optional) struct argument for
+/// an example.
+///
+/// # Struct arguments
+///
+/// ## `to_tokens` (optional)
+///
+/// Additionally generates a [`ToTokens`] implementation, rendering the parsed
+/// struct back into attribute's arguments, so it can be forwarded to another
+/// item (see [`ParseAttrs::to_attribute()`]) and parsed there again without
+/// any loss.
+///
+/// All the parsed values should implement [`ToTokens`] in such case, or be
+/// wrapped into a [`Spanning`] one, which is rendered with its held [`Span`]
+/// re-applied (see [`Spanning::to_respanned_tokens()`]). Note, that
+/// values of fields parsed `with` a custom function are rendered with their
+/// [`ToTokens`] implementation too, so they're parsed back only if the
+/// function accepts such an output.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::{ParseAttrs, ToTokens as _};
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// #[parse(to_tokens)]
+/// struct MyAttrs {
+///     #[parse(ident)]
+///     skip: Option<syn::Ident>,
+///
+///     #[parse(value)]
+///     rename: Option<syn::LitStr>,
+/// }
+///
+/// # fn main() {
+/// let input: syn::DeriveInput = parse_quote! {
+///     #[my_attr(skip, rename = "foo")]
+///     struct Dummy;
+/// };
+/// let my_attrs = MyAttrs::parse_attrs("my_attr", &input);
+///
+/// # assert!(my_attrs.is_ok());
+/// # let my_attrs = my_attrs.unwrap();
+/// assert_eq!(
+///     my_attrs.to_token_stream().to_string(),
+///     r#"skip , rename = "foo""#,
+/// );
+///
+/// let attr = my_attrs.to_attribute("other_attr");
+/// let generated: syn::DeriveInput = parse_quote! {
+///     #attr
+///     struct Generated;
+/// };
+/// let other_attrs = MyAttrs::parse_attrs("other_attr", &generated);
+///
+/// # assert!(other_attrs.is_ok());
+/// # let other_attrs = other_attrs.unwrap();
+/// assert_eq!(other_attrs.rename, Some(parse_quote!("foo")));
+/// # }
+/// ```
+///
+/// ## `builder` (optional)
+///
+/// Additionally generates a `<StructName>Builder` type, allowing to construct
+/// the struct programmatically, without parsing any tokens. Every field gets a
+/// setter method of the same name, while the `build()` method performs all the
+/// validations, the same way the parsing does, reporting errors for the
+/// attribute with the provided name.
+///
+/// Note, that fields named `build` or `new` are not allowed in such case.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::{ParseAttrs, Required};
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// #[parse(builder)]
+/// struct MyAttrs {
+///     #[parse(value)]
+///     rename: Required<syn::LitStr>,
+///
+///     #[parse(value)]
+///     aliases: Vec<syn::LitStr>,
+/// }
+///
+/// # fn main() {
+/// let my_attrs = MyAttrs::builder()
+///     .rename(parse_quote!("foo"))
+///     .aliases(parse_quote!("bar"))
+///     .aliases(parse_quote!("baz"))
+///     .build("my_attr");
+///
+/// # assert!(my_attrs.is_ok());
+/// # let my_attrs = my_attrs.unwrap();
+/// assert_eq!(my_attrs.rename.value(), "foo");
+/// assert_eq!(my_attrs.aliases.len(), 2);
+///
+/// let wrong =
+///     MyAttrs::builder().aliases(parse_quote!("bar")).build("my_attr");
+///
+/// assert!(wrong.is_err());
+/// # }
+/// ```
+///
+/// ## `inherit = <type>` (optional)
+///
+/// Additionally generates an [`Inherit`] implementation for the given parent
+/// [`ParseAttrs`] type (usually, the one placed on a container), applying the
+/// `inherit` field arguments. Such implementation is used by the
+/// [`tree::Container`] when parsing attributes of a whole item.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::{ParseAttrs, field, parse::attrs::Inherit as _};
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// struct ContainerAttrs {
+///     #[parse(ident)]
+///     skip: Option<syn::Ident>,
+///
+///     #[parse(value)]
+///     rename_all: Option<syn::LitStr>,
+/// }
+///
+/// #[derive(Default, ParseAttrs)]
+/// #[parse(inherit = ContainerAttrs)]
+/// struct FieldAttrs {
+///     #[parse(ident, inherit)]
+///     skip: Option<syn::Ident>,
+///
+///     #[parse(value, inherit = field::if_empty(
+///         |p: &ContainerAttrs| Ok(p.rename_all.clone()),
+///     ))]
+///     rename: Option<syn::LitStr>,
+/// }
+///
+/// # fn main() {
+/// let container: syn::DeriveInput = parse_quote! {
+///     #[my_attr(skip, rename_all = "camelCase")]
+///     struct Dummy;
+/// };
+/// let container = ContainerAttrs::parse_attrs("my_attr", &container);
+/// # assert!(container.is_ok());
+/// # let container = container.unwrap();
+///
+/// let field: syn::Field = parse_quote! {
+///     #[my_attr(rename = "foo")]
+///     field: u8
+/// };
+/// let my_attrs = FieldAttrs::parse_attrs("my_attr", &field);
+/// # assert!(my_attrs.is_ok());
+/// # let mut my_attrs = my_attrs.unwrap();
+///
+/// assert!(my_attrs.inherit(&container).is_ok());
+/// assert!(my_attrs.skip.is_some());
+/// assert_eq!(my_attrs.rename, Some(parse_quote!("foo")));
+/// # }
+/// ```
+///
+/// ## `lenient` (optional)
+///
+/// Makes the generated [`Parse`] implementation skip unknown arguments along
+/// with their bodies (like `name = value` or `name(...)`), instead of failing.
+/// Useful for picking only a few arguments out of an attribute of another
+/// crate (like `#[serde]`).
+///
+/// Note, that known arguments failing to be parsed (like
+/// `rename(serialize = "a")` for a `rename` field parsed as `value`) are
+/// skipped too, while duplicated ones are still rejected. An argument's body
+/// is skipped up to the next top-level comma.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::ParseAttrs;
+/// #
+/// #[derive(Default, ParseAttrs)]
+/// #[parse(lenient)]
+/// struct SerdeAttrs {
+///     #[parse(value)]
+///     rename: Option<syn::LitStr>,
+///
+///     #[parse(ident)]
+///     skip: Option<syn::Ident>,
+/// }
+///
+/// # fn main() {
+/// let input: syn::DeriveInput = parse_quote! {
+///     #[serde(rename = "foo", deny_unknown_fields)]
+///     #[serde(bound(serialize = "T: Serialize"), skip, default = "T::new")]
+///     struct Dummy;
+/// };
+/// let my_attrs = SerdeAttrs::parse_attrs("serde", &input);
+///
+/// # assert!(my_attrs.is_ok());
+/// # let my_attrs = my_attrs.unwrap();
+/// assert_eq!(my_attrs.rename, Some(parse_quote!("foo")));
+/// assert!(my_attrs.skip.is_some());
+/// # }
+/// ```
+///
 /// [`field::Container`]: synthez_core::field::Container
 /// [`field::if_empty()`]: synthez_core::field::if_empty
+/// [`Inherit`]: synthez_core::parse::attrs::Inherit
 /// [`Parse`]: syn::parse::Parse
+/// [`ParseAttrs`]: synthez_core::ParseAttrs
+/// [`ParseAttrs::schema()`]: synthez_core::ParseAttrs::schema
+/// [`ParseAttrs::to_attribute()`]: synthez_core::ParseAttrs::to_attribute
 /// [`Required`]: synthez_core::Required
+/// [`Schema::help()`]: synthez_core::parse::attrs::Schema::help
+/// [`Span`]: synthez_core::proc_macro2::Span
 /// [`Spanned`]: syn::spanned::Spanned
 /// [`Spanning`]: synthez_core::Spanning
+/// [`Spanning::to_respanned_tokens()`]:
+///     synthez_core::Spanning::to_respanned_tokens
 /// [`synthez::ParseAttrs`]: synthez_core::ParseAttrs
+/// [`ToTokens`]: synthez_core::quote::ToTokens
+/// [`tree::Container`]: synthez_core::parse::attrs::tree::Container
 #[proc_macro_derive(ParseAttrs, attributes(parse))]
 pub fn derive_parse_attrs(input: TokenStream) -> TokenStream {
     syn::parse(input)
@@ -486,14 +820,143 @@ pub fn derive_parse_attrs(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Deriving of [`synthez::ParseIdentEnum`] along with [`Parse`], [`ToTokens`]
+/// and [`Display`] implementations for a fieldless enum, representing its
+/// variants with identifiers (like `mode = strict`).
+///
+/// By default, variants are represented with their names converted into
+/// `snake_case`. Parsing an unknown value results in an error listing all the
+/// accepted values ([`ParseIdentEnum::VALUES`]). Use
+/// [`ParseIdentEnum::parse_spanned()`] to parse the [`Span`] of the value
+/// along.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::{ParseIdentEnum, quote::quote};
+/// #
+/// #[derive(Debug, Eq, ParseIdentEnum, PartialEq)]
+/// enum Mode {
+///     Strict,
+///     LooseChecks,
+/// }
+///
+/// # fn main() {
+/// let mode: Mode = parse_quote!(loose_checks);
+/// assert_eq!(mode, Mode::LooseChecks);
+/// assert_eq!(mode.to_string(), "loose_checks");
+/// assert_eq!(quote!(#mode).to_string(), "loose_checks");
+///
+/// let err = syn::parse_str::<Mode>("lenient").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "unknown value `lenient`, expected one of: `strict`, `loose_checks`",
+/// );
+/// # }
+/// ```
+///
+/// # Enum arguments
+///
+/// ## `rename_all = "<case>"` (optional)
+///
+/// Specifies the case to convert variants' names into: `"snake_case"`
+/// (default), `"camelCase"`, `"PascalCase"`, `"SCREAMING_SNAKE_CASE"` or
+/// `"kebab-case"`.
+///
+/// ## `from_str` (optional)
+///
+/// Allows values to be specified as string literals too (like
+/// `mode = "strict"`). Values not being valid identifiers (like
+/// `"kebab-case"` ones) can be parsed this way only, so they're rejected
+/// without this argument.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::ParseIdentEnum;
+/// #
+/// #[derive(Debug, Eq, ParseIdentEnum, PartialEq)]
+/// #[ident_enum(rename_all = "camelCase", from_str)]
+/// enum Case {
+///     SnakeCase,
+///     CamelCase,
+/// }
+///
+/// # fn main() {
+/// let from_ident: Case = parse_quote!(snakeCase);
+/// let from_str: Case = parse_quote!("camelCase");
+///
+/// assert_eq!(from_ident, Case::SnakeCase);
+/// assert_eq!(from_str, Case::CamelCase);
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// # use synthez::ParseIdentEnum;
+/// #
+/// #[derive(ParseIdentEnum)]
+/// #[ident_enum(rename_all = "kebab-case")]
+/// enum Wrong {
+///     /// `loose-checks` cannot be parsed without `from_str` argument.
+///     LooseChecks,
+/// }
+/// ```
+///
+/// # Variant arguments
+///
+/// ## `rename = <value>` (optional)
+///
+/// Specifies the value (either an identifier or a string literal) to represent
+/// the variant with, instead of its converted name.
+///
+/// ```rust
+/// # use syn::parse_quote;
+/// # use synthez::ParseIdentEnum;
+/// #
+/// #[derive(Debug, Eq, ParseIdentEnum, PartialEq)]
+/// enum Kind {
+///     #[ident_enum(rename = type)]
+///     Ty,
+///     Value,
+/// }
+///
+/// # fn main() {
+/// let kind: Kind = parse_quote!(type);
+///
+/// assert_eq!(kind, Kind::Ty);
+/// # }
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`Parse`]: syn::parse::Parse
+/// [`ParseIdentEnum::parse_spanned()`]:
+///     synthez_core::ParseIdentEnum::parse_spanned
+/// [`ParseIdentEnum::VALUES`]: synthez_core::ParseIdentEnum::VALUES
+/// [`Span`]: synthez_core::proc_macro2::Span
+/// [`synthez::ParseIdentEnum`]: synthez_core::ParseIdentEnum
+/// [`ToTokens`]: synthez_core::quote::ToTokens
+#[proc_macro_derive(ParseIdentEnum, attributes(ident_enum))]
+pub fn derive_parse_ident_enum(input: TokenStream) -> TokenStream {
+    syn::parse(input)
+        .and_then(|i| codegen::parse_ident_enum::derive(&i))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Deriving of a [`quote::ToTokens`] implementation.
 ///
+/// For enums without a top-level `append` argument, a `match` over the
+/// variants is generated, emitting fields of each variant in their order by
+/// default, which may be tuned with the
+/// [variant arguments](#variant-arguments).
+///
 /// # Arguments
 ///
-/// ## `append` (mandatory)
+/// ## `append` (mandatory for structs)
 ///
 /// Specifies methods to form [`ToTokens`]' output with.
 ///
+/// Cannot be used on enums along with the
+/// [variant arguments](#variant-arguments).
+///
 /// ```rust
 /// # use synthez::{proc_macro2::TokenStream, quote::quote, ToTokens};
 /// #
@@ -529,6 +992,51 @@ pub fn derive_parse_attrs(input: TokenStream) -> TokenStream {
 /// # }
 /// ```
 ///
+/// # Variant arguments
+///
+/// ## `fields(<field1>, <field2>)` (optional)
+///
+/// Specifies fields of the enum variant (by names or indices) to be emitted,
+/// in the specified order, instead of all of them.
+///
+/// ## `append(<method1>, <method2>)` (optional)
+///
+/// Specifies methods of the enum to be called after emitting the variant's
+/// `fields`. If no `fields` are specified, no fields are emitted at all.
+///
+/// ```rust
+/// # use synthez::{proc_macro2::TokenStream, quote::quote, syn, ToTokens};
+/// #
+/// #[derive(ToTokens)]
+/// enum Item {
+///     Ident(syn::Ident),
+///     #[to_tokens(fields(1, 0))]
+///     Reversed(syn::Ident, syn::Ident),
+///     #[to_tokens(fields(name), append(unit_tokens))]
+///     Typed { name: syn::Ident, ty: syn::Type },
+/// }
+///
+/// impl Item {
+///     fn unit_tokens(&self) -> TokenStream {
+///         quote! { : () }
+///     }
+/// }
+///
+/// # fn main() {
+/// let ident = Item::Ident(syn::parse_quote!(foo));
+/// let reversed = Item::Reversed(syn::parse_quote!(a), syn::parse_quote!(b));
+/// let typed = Item::Typed {
+///     name: syn::parse_quote!(bar),
+///     ty: syn::parse_quote!(u8),
+/// };
+///
+/// assert_eq!(
+///     quote! { #ident #reversed #typed }.to_string(),
+///     quote! { foo b a bar: () }.to_string(),
+/// );
+/// # }
+/// ```
+///
 /// [`quote::ToTokens`]: synthez_core::quote::ToTokens
 /// [`ToTokens`]: synthez_core::quote::ToTokens
 #[proc_macro_derive(ToTokens, attributes(to_tokens))]
@@ -0,0 +1,203 @@
+//! Conversion of identifiers and strings between different cases.
+//!
+//! ```rust
+//! # use synthez::case::Case;
+//! #
+//! assert_eq!(Case::Snake.convert("HTTPServerError"), "http_server_error");
+//! assert_eq!(Case::Camel.convert("http_server_error"), "httpServerError");
+//! assert_eq!(Case::Pascal.convert("http-server-error"), "HttpServerError");
+//! assert_eq!(Case::ScreamingSnake.convert("httpServer"), "HTTP_SERVER");
+//! assert_eq!(Case::Kebab.convert("HttpServer"), "http-server");
+//! ```
+//!
+//! [`IdentExt`] converts [`syn::Ident`]s the same way, producing raw
+//! identifiers on keyword collisions:
+//! ```rust
+//! # use synthez::{IdentExt as _, case::Case, syn};
+//! #
+//! let ident = syn::Ident::new_on_call_site("Type");
+//! assert_eq!(ident.to_case(Case::Snake).to_string(), "r#type");
+//!
+//! let raw = syn::Ident::new_raw("match", ident.span());
+//! assert_eq!(raw.to_case(Case::Pascal).to_string(), "Match");
+//! assert_eq!(raw.to_case_string(Case::ScreamingSnake), "MATCH");
+//! ```
+//!
+//! [`IdentExt`]: crate::ext::Ident
+//! [`syn::Ident`]: struct@syn::Ident
+
+use std::{fmt, str::FromStr};
+
+use syn::parse::{Parse, ParseStream};
+
+/// Case to convert identifiers and strings into.
+///
+/// Can be parsed from a string literal of its [`Case::name()`] (like
+/// `"snake_case"`), which is the same as in `#[serde(rename_all = "...")]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Case {
+    /// `snake_case`.
+    Snake,
+
+    /// `camelCase`.
+    Camel,
+
+    /// `PascalCase`.
+    Pascal,
+
+    /// `SCREAMING_SNAKE_CASE`.
+    ScreamingSnake,
+
+    /// `kebab-case`.
+    ///
+    /// Doesn't produce valid identifiers, so is meant for strings only.
+    Kebab,
+}
+
+impl Case {
+    /// All the existing [`Case`]s.
+    pub const ALL: [Self; 5] = [
+        Self::Snake,
+        Self::Camel,
+        Self::Pascal,
+        Self::ScreamingSnake,
+        Self::Kebab,
+    ];
+
+    /// Returns the name of this [`Case`], written in this [`Case`] itself.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Snake => "snake_case",
+            Self::Camel => "camelCase",
+            Self::Pascal => "PascalCase",
+            Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Self::Kebab => "kebab-case",
+        }
+    }
+
+    /// Converts the given `input` string into this [`Case`].
+    ///
+    /// Words are separated by `_`, `-`, whitespaces and case transitions (so
+    /// `HTTPServer` consists of `HTTP` and `Server` words). Leading `_`s are
+    /// preserved, as they're meaningful in Rust identifiers.
+    #[must_use]
+    pub fn convert(self, input: &str) -> String {
+        let trimmed = input.trim_start_matches('_');
+        let mut out = "_".repeat(input.len() - trimmed.len());
+
+        let separator = match self {
+            Self::Snake | Self::ScreamingSnake => "_",
+            Self::Kebab => "-",
+            Self::Camel | Self::Pascal => "",
+        };
+        for (n, word) in words(trimmed).enumerate() {
+            if n > 0 {
+                out.push_str(separator);
+            }
+            match self {
+                Self::Snake | Self::Kebab => out.push_str(&word.to_lowercase()),
+                Self::ScreamingSnake => out.push_str(&word.to_uppercase()),
+                Self::Camel if n == 0 => out.push_str(&word.to_lowercase()),
+                Self::Camel | Self::Pascal => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        out.extend(first.to_uppercase());
+                        out.push_str(&chars.as_str().to_lowercase());
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Case {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter().find(|c| c.name() == s).ok_or_else(|| {
+            let expected =
+                Self::ALL.map(|c| format!("\"{}\"", c.name())).join(", ");
+            format!("unknown case \"{s}\", expected one of: {expected}")
+        })
+    }
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        lit.value().parse().map_err(|e| syn::Error::new(lit.span(), e))
+    }
+}
+
+/// Splits the given `input` into words, separated by `_`, `-`, whitespaces
+/// and case transitions.
+fn words(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split(|c: char| c == '_' || c == '-' || c.is_whitespace())
+        .flat_map(split_case_transitions)
+        .filter(|w| !w.is_empty())
+}
+
+/// Splits the given `word` on its case transitions: `fooBar` into `foo` and
+/// `Bar`, and `HTTPServer` into `HTTP` and `Server`.
+fn split_case_transitions(word: &str) -> Vec<&str> {
+    let chars = word.char_indices().collect::<Vec<_>>();
+    let mut bounds = vec![0];
+    for (i, pair) in chars.windows(2).enumerate() {
+        let [(_, prev), (pos, curr)] = *pair else { continue };
+        let next = chars.get(i + 2).map(|(_, c)| *c);
+        let is_bound = (prev.is_lowercase() || prev.is_ascii_digit())
+            && curr.is_uppercase()
+            || prev.is_uppercase()
+                && curr.is_uppercase()
+                && next.is_some_and(char::is_lowercase);
+        if is_bound {
+            bounds.push(pos);
+        }
+    }
+    bounds.push(word.len());
+
+    bounds
+        .windows(2)
+        .filter_map(|b| match *b {
+            [from, to] => word.get(from..to),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Checks whether the given string is a Rust keyword, which cannot be used as
+/// a plain identifier.
+#[must_use]
+pub fn is_keyword(ident: &str) -> bool {
+    KEYWORDS.contains(&ident)
+}
+
+/// Checks whether the given string is a Rust keyword, which cannot be used
+/// even as a raw identifier (like `r#self`).
+#[must_use]
+pub fn is_non_raw_keyword(ident: &str) -> bool {
+    NON_RAW_KEYWORDS.contains(&ident)
+}
+
+/// Rust keywords (strict and reserved ones for the latest edition).
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break",
+    "const", "continue", "crate", "do", "dyn", "else", "enum", "extern",
+    "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try",
+    "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+    "yield",
+];
+
+/// Rust keywords, which cannot be used as raw identifiers.
+const NON_RAW_KEYWORDS: &[&str] = &["Self", "crate", "self", "super", "_"];
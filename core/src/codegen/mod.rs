@@ -1,4 +1,5 @@
 //! Code generation implementations.
 
 pub mod parse_attrs;
+pub mod parse_ident_enum;
 pub mod to_tokens;
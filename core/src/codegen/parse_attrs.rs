@@ -3,7 +3,7 @@
 use std::{collections::BTreeSet, iter};
 
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
@@ -13,8 +13,9 @@ use syn::{
 
 use crate::{
     ParseAttrs, Required, Spanning,
-    ext::{Data as _, Ident as _},
+    ext::{Data as _, Generics as _, Ident as _, Type as _},
     parse::{
+        attr,
         attrs::{
             dedup,
             field::TryMerge as _,
@@ -32,12 +33,17 @@ const TRAIT_NAME: &str = "ParseAttrs";
 /// Name of the helper attribute of this `proc_macro_derive`.
 const ATTR_NAME: &str = "parse";
 
+/// Names of fields not allowed along with `#[parse(builder)]` struct argument,
+/// as their setters would collide with the builder's own methods.
+const BUILDER_RESERVED: [&str; 2] = ["build", "new"];
+
 /// Expands `#[derive(ParseAttrs)]` proc macro.
 ///
 /// # Errors
 ///
 /// - If the proc macro isn't applied to a struct.
 /// - If parsing `#[parse]` helper attribute fails.
+/// - If a field name is reserved by `#[parse(builder)]` struct argument.
 pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     if !matches!(&input.data, syn::Data::Struct(_)) {
         return Err(syn::Error::new_spanned(
@@ -46,22 +52,61 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
         ));
     }
 
+    let attrs = StructAttrs::parse_attrs(ATTR_NAME, &input)?;
+
+    let fields = input
+        .data
+        .named_fields()?
+        .into_iter()
+        .map(Field::try_from)
+        .collect::<syn::Result<Vec<_>>>()?;
+    if attrs.inherit.is_none() {
+        if let Some(inherit) = fields.iter().find_map(|f| f.inherit.as_ref()) {
+            return Err(syn::Error::new(
+                inherit.span(),
+                "`inherit` field argument requires \
+                 `#[parse(inherit = <type>)]` struct argument",
+            ));
+        }
+    }
+
+    if attrs.builder.is_some() {
+        if let Some(f) = fields.iter().find(|f| {
+            BUILDER_RESERVED.contains(&f.ident.unraw().to_string().as_str())
+        }) {
+            return Err(syn::Error::new_spanned(
+                &f.ident,
+                format!(
+                    "`{}` field name is reserved by `#[parse(builder)]` struct \
+                     argument",
+                    f.ident.unraw(),
+                ),
+            ));
+        }
+    }
+
     let out = Definition {
+        vis: input.vis,
         ty: input.ident,
         generics: input.generics,
-        fields: input
-            .data
-            .named_fields()?
-            .into_iter()
-            .map(Field::try_from)
-            .collect::<syn::Result<Vec<_>>>()?,
+        fields,
+        to_tokens: attrs.to_tokens.is_some(),
+        builder: attrs.builder.is_some(),
+        inherit: attrs.inherit,
+        lenient: attrs.lenient.is_some(),
     };
 
     let impl_syn_parse = out.impl_syn_parse();
     let impl_parse_attrs = out.impl_parse_attrs();
+    let impl_to_tokens = out.impl_to_tokens();
+    let impl_inherit = out.impl_inherit();
+    let builder = out.gen_builder();
     Ok(quote! {
         #impl_syn_parse
         #impl_parse_attrs
+        #impl_to_tokens
+        #impl_inherit
+        #builder
     })
 }
 
@@ -69,6 +114,9 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<TokenStream> {
 /// generation.
 #[derive(Debug)]
 struct Definition {
+    /// [`syn::Visibility`] of this structure's type.
+    vis: syn::Visibility,
+
     /// [`syn::Ident`] of this structure's type.
     ///
     /// [`syn::Ident`]: struct@syn::Ident
@@ -79,79 +127,100 @@ struct Definition {
 
     /// [`Field`]s of this structure to generate code for.
     fields: Vec<Field>,
+
+    /// Indicator whether a [`ToTokens`] implementation should be generated for
+    /// this structure.
+    to_tokens: bool,
+
+    /// Indicator whether a builder should be generated for this structure.
+    builder: bool,
+
+    /// Type of the parent [`ParseAttrs`] to generate an [`Inherit`]
+    /// implementation for, if any.
+    ///
+    /// [`Inherit`]: crate::parse::attrs::Inherit
+    inherit: Option<syn::Type>,
+
+    /// Indicator whether unknown [`syn::Attribute`]'s arguments (or known ones
+    /// failing to be parsed) should be skipped instead of failing the parsing.
+    lenient: bool,
 }
 
 impl Definition {
     /// Generates implementation of [`Parse`] trait for this struct.
     #[must_use]
     fn impl_syn_parse(&self) -> TokenStream {
-        let parse_arms = self.fields.iter().map(|f| {
-            let field = &f.ident;
-            let ty = &f.ty;
-            let kind = f.kind;
-            let dedup = f.dedup;
-            let arg_lits = &f.names;
+        let parse_arms = self.fields.iter().map(Field::gen_parse_arm);
 
-            let val_ty = quote! {
-                <#ty as ::synthez::field::Container<_>>::Value
-            };
+        let ty = &self.ty;
+        let (impl_generics, ty_generics, where_clause) =
+            self.generics.split_for_impl();
+        let help = quote! {
+            &<Self as ::synthez::parse::Attrs>::schema().help()
+        };
 
-            let code = match kind {
-                Kind::Ident => quote! {
-                    <#ty as ::synthez::parse::attrs::field::TryApply<
-                        _, #kind, #dedup,
-                    >>::try_apply(&mut out.#field, input.parse::<#val_ty>()?)?;
-                },
-                Kind::Nested => quote! {
-                    ::synthez::ParseBufferExt::skip_any_ident(input)?;
-                    let inner;
-                    let _ = ::synthez::syn::parenthesized!(inner in input);
-                    <#ty as ::synthez::parse::attrs::field::TryApply<
-                        _, #kind, #dedup,
-                    >>::try_apply(
-                        &mut out.#field,
-                        ::synthez::Spanning::new(inner.parse()?, &ident),
+        let parse_arg = if self.lenient {
+            quote! {
+                let fork = input.fork();
+                let parse = |input: ::synthez::syn::parse::ParseStream<'_>| {
+                    let mut out =
+                        <Self as ::std::default::Default>::default();
+                    let ident = ::synthez::ParseBufferExt::parse_any_ident(
+                        &input.fork(),
                     )?;
-                },
-                Kind::Value(spaced) => {
-                    let method = syn::Ident::new_on_call_site(if spaced {
-                        "parse_maybe_wrapped_and_punctuated"
-                    } else {
-                        "parse_eq_or_wrapped_and_punctuated"
-                    });
-
-                    quote! {
-                        ::synthez::ParseBufferExt::skip_any_ident(input)?;
-                        for v in ::synthez::ParseBufferExt::#method::<
-                            #val_ty,
-                            ::synthez::syn::token::Paren,
-                            ::synthez::syn::token::Comma,
-                        >(input)? {
-                            <#ty as ::synthez::parse::attrs::field::TryApply<
-                                _, #kind, #dedup,
-                            >>::try_apply(&mut out.#field, v)?;
-                        }
+                    match ident.to_string().as_str() {
+                        #( #parse_arms )*
+                        name => {
+                            return Err(::synthez::parse::err::unknown_attr_arg(
+                                &ident, name,
+                            ));
+                        },
+                    }
+                    if !input.is_empty()
+                        && !input.peek(::synthez::syn::token::Comma)
+                    {
+                        return Err(::synthez::parse::err::
+                            expected_followed_by_comma(&ident));
                     }
+                    ::synthez::syn::Result::Ok(out)
+                };
+                if let Ok(parsed) = parse(&fork) {
+                    ::synthez::syn::parse::discouraged::Speculative::advance_to(
+                        input, &fork,
+                    );
+                    out = <Self as ::synthez::parse::Attrs>::try_merge(
+                        out, parsed,
+                    )?;
+                } else {
+                    ::synthez::ParseBufferExt::skip_any_arg(input)?;
                 }
-                Kind::Map => quote! {
-                    ::synthez::ParseBufferExt::skip_any_ident(input)?;
-                    let k = input.parse()?;
-                    input.parse::<::synthez::syn::token::Eq>()?;
-                    let v = input.parse()?;
-                    <#ty as ::synthez::parse::attrs::field::TryApply<
-                        (_, _), #kind, #dedup,
-                    >>::try_apply(&mut out.#field, (k, v))?;
-                },
-            };
-
+                ::synthez::ParseBufferExt::try_parse::<
+                    ::synthez::syn::token::Comma,
+                >(input)?;
+            }
+        } else {
             quote! {
-                #( #arg_lits )|* => { #code },
+                let ident =
+                    ::synthez::ParseBufferExt::parse_any_ident(&input.fork())?;
+                match ident.to_string().as_str() {
+                    #( #parse_arms )*
+                    name => {
+                        return Err(::synthez::parse::err::with_help(
+                            ::synthez::parse::err::unknown_attr_arg(
+                                &ident, name,
+                            ),
+                            #help,
+                        ));
+                    },
+                }
+                if ::synthez::ParseBufferExt::try_parse::<
+                    ::synthez::syn::token::Comma,
+                >(input)?.is_none() && !input.is_empty() {
+                    return Err(::synthez::parse::err::
+                        expected_followed_by_comma(&ident));
+                }
             }
-        });
-
-        let ty = &self.ty;
-        let (impl_generics, ty_generics, where_clause) =
-            self.generics.split_for_impl();
+        };
 
         quote! {
             #[automatically_derived]
@@ -166,23 +235,7 @@ impl Definition {
                         <#ty #ty_generics as ::std::default::Default>
                             ::default();
                     while !input.is_empty() {
-                        let ident =
-                            ::synthez::ParseBufferExt::parse_any_ident(
-                                &input.fork(),
-                            )?;
-                        match ident.to_string().as_str() {
-                            #( #parse_arms )*
-                            name => {
-                                return Err(::synthez::parse::err::
-                                    unknown_attr_arg(&ident, name));
-                            },
-                        }
-                        if ::synthez::ParseBufferExt::try_parse::<
-                            ::synthez::syn::token::Comma,
-                        >(input)?.is_none() && !input.is_empty() {
-                            return Err(::synthez::parse::err::
-                                expected_followed_by_comma(&ident));
-                        }
+                        #parse_arg
                     }
                     Ok(out)
                 }
@@ -212,6 +265,13 @@ impl Definition {
             })
         });
 
+        let schema_empty = (!self.fields.is_empty()).then(|| {
+            quote! {
+                let empty = <Self as ::std::default::Default>::default();
+            }
+        });
+        let schema_args = self.fields.iter().map(Field::gen_schema_arg);
+
         let fallback_nested_fields =
             self.fields.iter().filter_map(Field::gen_fallback_nested);
         let fallback_custom_fields = self.fields.iter().flat_map(|f| {
@@ -255,8 +315,164 @@ impl Definition {
                     #( #fallback_custom_fields )*
                     Ok(())
                 }
+
+                fn schema() -> ::synthez::parse::attrs::Schema {
+                    #schema_empty
+                    ::synthez::parse::attrs::Schema {
+                        args: ::std::vec![#( #schema_args ),*],
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates implementation of [`ToTokens`] trait for this struct, if it's
+    /// requested.
+    #[must_use]
+    fn impl_to_tokens(&self) -> Option<TokenStream> {
+        if !self.to_tokens {
+            return None;
+        }
+
+        let ty = &self.ty;
+        let (impl_generics, ty_generics, where_clause) =
+            self.generics.split_for_impl();
+
+        let render_fields = self.fields.iter().map(Field::gen_to_args);
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::synthez::quote::ToTokens for #ty #ty_generics
+                 #where_clause
+            {
+                fn to_tokens(
+                    &self,
+                    out: &mut ::synthez::proc_macro2::TokenStream,
+                ) {
+                    let mut args = ::synthez::syn::punctuated::Punctuated::<
+                        ::synthez::proc_macro2::TokenStream,
+                        ::synthez::syn::token::Comma,
+                    >::new();
+                    #( #render_fields )*
+                    ::synthez::quote::ToTokens::to_tokens(&args, out);
+                }
+            }
+        })
+    }
+
+    /// Generates implementation of [`Inherit`] trait for this struct, if it's
+    /// requested.
+    ///
+    /// [`Inherit`]: crate::parse::attrs::Inherit
+    #[must_use]
+    fn impl_inherit(&self) -> Option<TokenStream> {
+        let parent = self.inherit.as_ref()?;
+
+        let ty = &self.ty;
+        let (impl_generics, ty_generics, where_clause) =
+            self.generics.split_for_impl();
+
+        let inherit_fields = self.fields.iter().filter_map(Field::gen_inherit);
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::synthez::parse::attrs::Inherit<#parent>
+             for #ty #ty_generics #where_clause
+            {
+                fn inherit(
+                    &mut self,
+                    parent: &#parent,
+                ) -> ::synthez::syn::Result<()> {
+                    #( #inherit_fields )*
+                    Ok(())
+                }
             }
+        })
+    }
+
+    /// Generates a builder type for this struct along with its implementation,
+    /// if it's requested.
+    #[must_use]
+    fn gen_builder(&self) -> Option<TokenStream> {
+        if !self.builder {
+            return None;
         }
+
+        let vis = &self.vis;
+        let ty = &self.ty;
+        let builder = format_ident!("{ty}Builder");
+        let generics = &self.generics;
+        let (impl_generics, ty_generics, where_clause) =
+            self.generics.split_for_impl();
+
+        let builder_doc = format!("Builder of a [`{ty}`].");
+        let builder_fn_doc = format!(
+            "Creates a new [`{builder}`] of a [`{ty}`] with no values set.",
+        );
+        let build_doc = format!(
+            "Builds a [`{ty}`] out of this [`{builder}`], validating it as \
+             the attribute with the provided `attr_name`.\n\n\
+             # Errors\n\n\
+             If the built [`{ty}`] fails to be validated.",
+        );
+
+        let setters = self.fields.iter().map(|f| {
+            let field = &f.ident;
+            let field_ty = &f.ty;
+            let doc = format!(
+                "Sets the provided `value` into the `{}` field.",
+                field.unraw(),
+            );
+
+            let setter_generics =
+                self.generics.with_predicates(iter::once(syn::parse_quote! {
+                    #field_ty: ::synthez::field::Container<__V>
+                }));
+            let setter_where_clause = &setter_generics.where_clause;
+
+            quote! {
+                #[doc = #doc]
+                #[must_use]
+                #vis fn #field<__V>(mut self, value: __V) -> Self
+                    #setter_where_clause
+                {
+                    ::synthez::field::Container::set(&mut self.0.#field, value);
+                    self
+                }
+            }
+        });
+
+        Some(quote! {
+            #[doc = #builder_doc]
+            #vis struct #builder #generics (#ty #ty_generics) #where_clause;
+
+            #[automatically_derived]
+            impl #impl_generics #ty #ty_generics #where_clause {
+                #[doc = #builder_fn_doc]
+                #[must_use]
+                #vis fn builder() -> #builder #ty_generics {
+                    #builder(::std::default::Default::default())
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #builder #ty_generics #where_clause {
+                #( #setters )*
+
+                #[doc = #build_doc]
+                #vis fn build(
+                    self,
+                    attr_name: &str,
+                ) -> ::synthez::syn::Result<#ty #ty_generics> {
+                    ::synthez::parse::Attrs::validate(
+                        &self.0,
+                        attr_name,
+                        ::synthez::proc_macro2::Span::call_site(),
+                    )?;
+                    Ok(self.0)
+                }
+            }
+        })
     }
 }
 
@@ -281,6 +497,10 @@ struct Field {
     /// code.
     dedup: Dedup,
 
+    /// Primary name of [`syn::Attribute`]'s argument to parse this [`Field`]
+    /// from, used when describing or rendering this [`Field`].
+    name: String,
+
     /// Names [`syn::Attribute`]'s arguments to parse this [`Field`] from in the
     /// generated code.
     names: Vec<String>,
@@ -292,6 +512,17 @@ struct Field {
     /// Additional custom fallback functions to apply to this [`Field`] in the
     /// generated code.
     fallbacks: Vec<syn::Expr>,
+
+    /// Custom function to parse this [`Field`]'s values with in the generated
+    /// code, instead of the [`Parse`] implementation.
+    with: Option<syn::Expr>,
+
+    /// Way of inheriting this [`Field`]'s value from the parent
+    /// [`ParseAttrs`] in the generated code, if any.
+    inherit: Option<Spanning<Inherit>>,
+
+    /// Documentation of this [`Field`], if any.
+    doc: Option<String>,
 }
 
 impl TryFrom<syn::Field> for Field {
@@ -299,12 +530,18 @@ impl TryFrom<syn::Field> for Field {
 
     fn try_from(field: syn::Field) -> syn::Result<Self> {
         let attrs = FieldAttrs::parse_attrs(ATTR_NAME, &field)?;
+        let doc = attr::doc_string(&field.attrs)?.map(Spanning::into_inner);
 
         let field_span = field.span();
         let ident = field.ident.ok_or_else(move || {
             syn::Error::new(field_span, "Named field expected")
         })?;
 
+        let name = attrs
+            .args
+            .first()
+            .map_or_else(|| ident.unraw(), Clone::clone)
+            .to_string();
         let mut names = if attrs.args.is_empty() {
             iter::once(ident.unraw()).collect()
         } else {
@@ -312,19 +549,160 @@ impl TryFrom<syn::Field> for Field {
         };
         names.try_merge_self::<kind::Value, dedup::Unique>(attrs.aliases)?;
 
+        if matches!(**attrs.kind, Kind::Value { from_str: true, .. })
+            && attrs.with.is_none()
+            && holds_strings(&field.ty)
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`from_str` value argument cannot be used for string values \
+                 without `with` argument, as they're string literals already",
+            ));
+        }
+
         Ok(Self {
             ident,
             ty: field.ty,
             kind: **attrs.kind,
             dedup: attrs.dedup.as_deref().copied().unwrap_or_default(),
+            name,
             names: names.into_iter().map(|n| n.to_string()).collect(),
             validators: attrs.validators,
             fallbacks: attrs.fallbacks,
+            with: attrs.with,
+            inherit: attrs.inherit,
+            doc,
         })
     }
 }
 
+/// Checks whether the given [`syn::Type`] of a [`Field`] holds string values
+/// (like `Option<syn::LitStr>` or `Vec<String>`), looking through the generic
+/// arguments of its containers.
+fn holds_strings(ty: &syn::Type) -> bool {
+    ty.last_segment_ident().is_some_and(|ident| {
+        ident == "LitStr"
+            || ident == "String"
+            || ty
+                .generic_args_of(&ident.to_string())
+                .is_some_and(|args| args.into_iter().any(holds_strings))
+    })
+}
+
 impl Field {
+    /// Generates code of the `match` arm parsing this [`Field`] in the
+    /// [`Parse`] implementation.
+    #[must_use]
+    fn gen_parse_arm(&self) -> TokenStream {
+        let field = &self.ident;
+        let ty = &self.ty;
+        let kind = self.kind;
+        let dedup = self.dedup;
+        let arg_lits = &self.names;
+
+        let val_ty = quote! {
+            <#ty as ::synthez::field::Container<_>>::Value
+        };
+
+        let code = match kind {
+            Kind::Ident => {
+                let val = self.with.as_ref().map_or_else(
+                    || quote! { input.parse::<#val_ty>()? },
+                    |with| quote! { (#with)(input)? },
+                );
+
+                quote! {
+                    <#ty as ::synthez::parse::attrs::field::TryApply<
+                        _, #kind, #dedup,
+                    >>::try_apply(&mut out.#field, #val)?;
+                }
+            }
+            Kind::Nested => {
+                let val = self.with.as_ref().map_or_else(
+                    || quote! { inner.parse()? },
+                    |with| quote! { (#with)(&inner)? },
+                );
+
+                quote! {
+                    ::synthez::ParseBufferExt::skip_any_ident(input)?;
+                    let inner;
+                    let _ = ::synthez::syn::parenthesized!(inner in input);
+                    <#ty as ::synthez::parse::attrs::field::TryApply<
+                        _, #kind, #dedup,
+                    >>::try_apply(
+                        &mut out.#field,
+                        ::synthez::Spanning::new(#val, &ident),
+                    )?;
+                }
+            }
+            Kind::Value { spaced, from_str } => {
+                let method = if spaced {
+                    "parse_maybe_wrapped_and_punctuated"
+                } else {
+                    "parse_eq_or_wrapped_and_punctuated"
+                };
+                let parser = if from_str {
+                    let with = self.with.as_ref().map_or_else(
+                        || {
+                            quote! {
+                                <#val_ty as ::synthez::syn::parse::Parse>::parse
+                            }
+                        },
+                        ToTokens::to_token_stream,
+                    );
+                    Some(quote! {
+                        |stream| ::synthez::ParseBufferExt::
+                            parse_maybe_str_with(stream, #with)
+                    })
+                } else {
+                    self.with.as_ref().map(ToTokens::to_token_stream)
+                };
+                let method = if parser.is_some() {
+                    format_ident!("{method}_with")
+                } else {
+                    syn::Ident::new_on_call_site(method)
+                };
+
+                quote! {
+                    ::synthez::ParseBufferExt::skip_any_ident(input)?;
+                    for v in ::synthez::ParseBufferExt::#method::<
+                        #val_ty,
+                        ::synthez::syn::token::Paren,
+                        ::synthez::syn::token::Comma,
+                    >(input, #parser)? {
+                        <#ty as ::synthez::parse::attrs::field::TryApply<
+                            _, #kind, #dedup,
+                        >>::try_apply(&mut out.#field, v)?;
+                    }
+                }
+            }
+            Kind::Map => {
+                let val = self.with.as_ref().map_or_else(
+                    || {
+                        quote! {{
+                            let k = input.parse()?;
+                            input.parse::<::synthez::syn::token::Eq>()?;
+                            let v = input.parse()?;
+                            (k, v)
+                        }}
+                    },
+                    |with| quote! { (#with)(input)? },
+                );
+
+                quote! {
+                    ::synthez::ParseBufferExt::skip_any_ident(input)?;
+                    <#ty as ::synthez::parse::attrs::field::TryApply<
+                        (_, _), #kind, #dedup,
+                    >>::try_apply(&mut out.#field, #val)?;
+                }
+            }
+        };
+
+        quote! {
+            #( #arg_lits )|* => { #code },
+        }
+    }
+
     /// Generates code of merging this [`Field`] with another one.
     #[must_use]
     fn gen_merge(&self) -> TokenStream {
@@ -340,6 +718,85 @@ impl Field {
         }
     }
 
+    /// Generates code of rendering this [`Field`] back into
+    /// [`syn::Attribute`]'s arguments.
+    #[must_use]
+    fn gen_to_args(&self) -> TokenStream {
+        let field = &self.ident;
+        let ty = &self.ty;
+        let kind = self.kind;
+        let name = &self.name;
+
+        let val_ty = if self.kind == Kind::Map {
+            quote! { (_, _) }
+        } else {
+            quote! { _ }
+        };
+
+        if let Kind::Value { spaced: true, .. } = self.kind {
+            return quote! {
+                <#ty as ::synthez::parse::attrs::field::ToSpacedArgs<_>>
+                    ::to_spaced_args(&self.#field, #name, &mut args);
+            };
+        }
+        quote! {
+            <#ty as ::synthez::parse::attrs::field::ToArgs<
+                #val_ty, #kind,
+            >>::to_args(&self.#field, #name, &mut args);
+        }
+    }
+
+    /// Generates code of [`Schema`] argument describing this [`Field`].
+    ///
+    /// Expects an `empty` variable of the [`ParseAttrs`] struct with
+    /// [`Default`] values to be present in the scope.
+    ///
+    /// [`Schema`]: crate::parse::attrs::Schema
+    #[must_use]
+    fn gen_schema_arg(&self) -> TokenStream {
+        let field = &self.ident;
+        let ty = &self.ty;
+        let name = &self.name;
+        let aliases = self.names.iter().filter(|n| **n != self.name);
+        let dedup = self.dedup.schema_tokens();
+
+        let kind = match self.kind {
+            Kind::Ident => quote! { Ident },
+            Kind::Nested => quote! { Nested },
+            Kind::Value { spaced, .. } => quote! { Value { spaced: #spaced } },
+            Kind::Map => quote! { Map },
+        };
+        let nested = if self.kind == Kind::Nested {
+            quote! {
+                ::std::option::Option::Some(
+                    <<<#ty as ::synthez::field::Container<_>>::Value
+                        as ::std::ops::Deref>::Target
+                        as ::synthez::parse::Attrs>::schema,
+                )
+            }
+        } else {
+            quote! { ::std::option::Option::None }
+        };
+        let doc = self.doc.as_ref().map_or_else(
+            || quote! { ::std::option::Option::None },
+            |d| quote! { ::std::option::Option::Some(#d) },
+        );
+
+        quote! {
+            ::synthez::parse::attrs::schema::Arg {
+                name: #name,
+                aliases: &[#( #aliases ),*],
+                kind: ::synthez::parse::attrs::schema::Kind::#kind,
+                dedup: #dedup,
+                required: <#ty as ::synthez::parse::attrs::Validation<
+                    ::synthez::parse::attrs::validate::rule::Provided,
+                >>::validation(&empty.#field).is_err(),
+                doc: #doc,
+                nested: #nested,
+            }
+        }
+    }
+
     /// Generates code of [`rule::Provided`] validation for this [`Field`].
     #[must_use]
     fn gen_validate_provided(&self) -> TokenStream {
@@ -414,6 +871,117 @@ impl Field {
             }
         })
     }
+
+    /// Generates code inheriting this [`Field`]'s value from the parent
+    /// [`ParseAttrs`], if it's requested.
+    ///
+    /// Bare `inherit` clones the whole parent's [`field::Container`] (all the
+    /// values of a [`Vec`], for example), so cannot reuse
+    /// [`field::if_empty()`], which sets a single value only.
+    ///
+    /// [`field::Container`]: crate::field::Container
+    /// [`field::if_empty()`]: crate::field::if_empty
+    #[must_use]
+    fn gen_inherit(&self) -> Option<TokenStream> {
+        let field = &self.ident;
+        let ty = &self.ty;
+
+        Some(match &**self.inherit.as_ref()? {
+            Inherit::Same => quote! {
+                if <#ty as ::synthez::field::Container<_>>::is_empty(
+                    &self.#field,
+                ) {
+                    ::std::clone::Clone::clone_from(
+                        &mut self.#field,
+                        &parent.#field,
+                    );
+                }
+            },
+            Inherit::With(func) => quote! {
+                (#func)(&mut self.#field, parent)?;
+            },
+        })
+    }
+}
+
+/// Representation of a `#[parse]` attribute used along with a
+/// `#[derive(ParseAttrs)]` proc macro and placed on a struct.
+#[derive(Debug, Default)]
+struct StructAttrs {
+    /// Indicator whether a [`ToTokens`] implementation should be generated for
+    /// the [`ParseAttrs`] struct.
+    // #[parse(ident)]
+    to_tokens: Option<syn::Ident>,
+
+    /// Indicator whether a builder should be generated for the [`ParseAttrs`]
+    /// struct.
+    // #[parse(ident)]
+    builder: Option<syn::Ident>,
+
+    /// Type of the parent [`ParseAttrs`] struct to inherit values from.
+    // #[parse(value)]
+    inherit: Option<syn::Type>,
+
+    /// Indicator whether unknown arguments should be skipped while parsing
+    /// the [`ParseAttrs`] struct.
+    // #[parse(ident)]
+    lenient: Option<syn::Ident>,
+}
+
+impl Parse for StructAttrs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.fork().parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "to_tokens" => {
+                    out.to_tokens.try_merge::<kind::Ident, dedup::Unique>(
+                        input.parse_any_ident()?,
+                    )?;
+                }
+                "builder" => {
+                    out.builder.try_merge::<kind::Ident, dedup::Unique>(
+                        input.parse_any_ident()?,
+                    )?;
+                }
+                "lenient" => {
+                    out.lenient.try_merge::<kind::Ident, dedup::Unique>(
+                        input.parse_any_ident()?,
+                    )?;
+                }
+                "inherit" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated::<
+                        syn::Type, token::Paren, token::Comma,
+                    >()? {
+                        out.inherit.try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                name => {
+                    return Err(err::unknown_attr_arg(&ident, name));
+                }
+            }
+            if input.try_parse::<token::Comma>()?.is_none() && !input.is_empty()
+            {
+                return Err(err::expected_followed_by_comma(&ident));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl ParseAttrs for StructAttrs {
+    fn try_merge(mut self, another: Self) -> syn::Result<Self> {
+        self.to_tokens
+            .try_merge_self::<kind::Ident, dedup::Unique>(another.to_tokens)?;
+        self.builder
+            .try_merge_self::<kind::Ident, dedup::Unique>(another.builder)?;
+        self.inherit
+            .try_merge_self::<kind::Value, dedup::Unique>(another.inherit)?;
+        self.lenient
+            .try_merge_self::<kind::Ident, dedup::Unique>(another.lenient)?;
+        Ok(self)
+    }
 }
 
 /// Representation of a `#[parse]` attribute used along with a
@@ -453,6 +1021,15 @@ struct FieldAttrs {
     /// field.
     // #[parse(value, alias = fallback)]
     fallbacks: Vec<syn::Expr>,
+
+    /// Custom function to parse the [`ParseAttrs`]'s field values with.
+    // #[parse(value)]
+    with: Option<syn::Expr>,
+
+    /// Way of inheriting the [`ParseAttrs`]'s field value from the parent
+    /// [`ParseAttrs`].
+    // #[parse(ident, value)]
+    inherit: Option<Spanning<Inherit>>,
 }
 
 impl Parse for FieldAttrs {
@@ -510,6 +1087,33 @@ impl Parse for FieldAttrs {
                         >(v)?;
                     }
                 }
+                "with" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated::<
+                        syn::Expr, token::Paren, token::Comma,
+                    >()? {
+                        out.with.try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                "inherit" => {
+                    let arg = input.parse_any_ident()?;
+                    if input.is_next::<token::Eq>()
+                        || input.is_next::<token::Paren>()
+                    {
+                        for v in input.parse_eq_or_wrapped_and_punctuated::<
+                            syn::Expr, token::Paren, token::Comma,
+                        >()? {
+                            let span = v.span();
+                            out.inherit.try_merge::<
+                                kind::Value, dedup::Unique,
+                            >(Spanning::new(Inherit::With(Box::new(v)), span))?;
+                        }
+                    } else {
+                        out.inherit.try_merge::<kind::Ident, dedup::Unique>(
+                            Spanning::new(Inherit::Same, &arg),
+                        )?;
+                    }
+                }
                 name => {
                     return Err(err::unknown_attr_arg(&ident, name));
                 }
@@ -535,6 +1139,9 @@ impl ParseAttrs for FieldAttrs {
             .try_merge_self::<kind::Value, dedup::Unique>(another.validators)?;
         self.fallbacks
             .try_merge_self::<kind::Value, dedup::Unique>(another.fallbacks)?;
+        self.with.try_merge_self::<kind::Value, dedup::Unique>(another.with)?;
+        self.inherit
+            .try_merge_self::<kind::Value, dedup::Unique>(another.inherit)?;
         Ok(self)
     }
 
@@ -565,11 +1172,18 @@ enum Kind {
 
     /// Field is parsed as values behind a [`syn::Ident`].
     ///
-    /// Boolean refers to whether the value and the [`syn::Ident`] are separated
-    /// with spaces only.
-    ///
     /// [`syn::Ident`]: struct@syn::Ident
-    Value(bool),
+    Value {
+        /// Indicator whether the value and the [`syn::Ident`] are separated
+        /// with spaces only.
+        ///
+        /// [`syn::Ident`]: struct@syn::Ident
+        spaced: bool,
+
+        /// Indicator whether the value may be also specified inside a string
+        /// literal.
+        from_str: bool,
+    },
 
     /// Field is parsed as as key-value pairs behind a [`syn::Ident`].
     ///
@@ -585,21 +1199,30 @@ impl Parse for Spanning<Kind> {
                 "ident" => Kind::Ident,
                 "nested" => Kind::Nested,
                 "value" => {
+                    let (mut spaced, mut from_str) = (false, false);
                     if input.is_next::<token::Paren>() {
-                        let inner;
-                        _ = syn::parenthesized!(inner in input);
-                        let inner = inner.parse::<syn::Ident>()?;
-                        let val = inner.to_string();
-                        if val != "spaced" {
-                            return Err(syn::Error::new_spanned(
-                                inner,
-                                format!("invalid value setting: {val} "),
-                            ));
+                        for setting in input.parse_wrapped_and_punctuated::<
+                            syn::Ident, token::Paren, token::Comma,
+                        >()? {
+                            let flag = match setting.to_string().as_str() {
+                                "spaced" => &mut spaced,
+                                "from_str" => &mut from_str,
+                                val => {
+                                    return Err(syn::Error::new_spanned(
+                                        setting,
+                                        format!(
+                                            "invalid value setting: {val} ",
+                                        ),
+                                    ));
+                                }
+                            };
+                            if *flag {
+                                return Err(err::dup_attr_arg(&setting));
+                            }
+                            *flag = true;
                         }
-                        Kind::Value(true)
-                    } else {
-                        Kind::Value(false)
                     }
+                    Kind::Value { spaced, from_str }
                 }
                 "map" => Kind::Map,
                 val => {
@@ -619,7 +1242,7 @@ impl ToTokens for Kind {
         let variant = syn::Ident::new_on_call_site(match self {
             Self::Ident => "Ident",
             Self::Nested => "Nested",
-            Self::Value(_) => "Value",
+            Self::Value { .. } => "Value",
             Self::Map => "Map",
         });
         (quote! {
@@ -629,6 +1252,17 @@ impl ToTokens for Kind {
     }
 }
 
+/// Way of inheriting a field value from a parent [`ParseAttrs`].
+#[derive(Debug)]
+enum Inherit {
+    /// Value of the parent's field with the same name is cloned, if the field
+    /// is empty.
+    Same,
+
+    /// Custom function is applied to the field and the parent.
+    With(Box<syn::Expr>),
+}
+
 /// Field [`dedup`]lication strategy parsed from [`syn::Attribute`]s.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 enum Dedup {
@@ -663,6 +1297,23 @@ impl Parse for Spanning<Dedup> {
     }
 }
 
+impl Dedup {
+    /// Generates code of the [`schema::Dedup`] matching this [`Dedup`].
+    ///
+    /// [`schema::Dedup`]: crate::parse::attrs::schema::Dedup
+    #[must_use]
+    fn schema_tokens(self) -> TokenStream {
+        let variant = syn::Ident::new_on_call_site(match self {
+            Self::Unique => "Unique",
+            Self::First => "First",
+            Self::Last => "Last",
+        });
+        quote! {
+            ::synthez::parse::attrs::schema::Dedup::#variant
+        }
+    }
+}
+
 impl ToTokens for Dedup {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let variant = syn::Ident::new_on_call_site(match self {
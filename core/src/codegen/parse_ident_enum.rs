@@ -0,0 +1,352 @@
+//! `#[derive(ParseIdentEnum)]` proc macro implementation.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream, Parser as _},
+    token,
+};
+
+use crate::{
+    ParseAttrs,
+    case::Case,
+    ext::{Data as _, Ident as _},
+    parse::{
+        attrs::{dedup, field::TryMerge as _, kind},
+        err,
+        ext::ParseBuffer as _,
+    },
+    spanned::Spanning,
+};
+
+/// Name of the derived trait.
+const TRAIT_NAME: &str = "ParseIdentEnum";
+
+/// Name of the helper attribute of this `proc_macro_derive`.
+const ATTR_NAME: &str = "ident_enum";
+
+/// Expands `#[derive(ParseIdentEnum)]` proc macro.
+///
+/// # Errors
+///
+/// - If the proc macro isn't applied to an enum.
+/// - If the enum has variants with fields.
+/// - If parsing `#[ident_enum]` helper attribute fails.
+/// - If several variants are represented by the same value.
+/// - If a variant is represented by a value not being a valid identifier,
+///   while `from_str` argument is not specified.
+pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let variants = input.data.unit_variants_only().map_err(|e| {
+        if matches!(input.data, syn::Data::Enum(_)) {
+            e
+        } else {
+            syn::Error::new_spanned(
+                input,
+                format!("only fieldless enums can derive {TRAIT_NAME}"),
+            )
+        }
+    })?;
+
+    let attrs = EnumAttrs::parse_attrs(ATTR_NAME, input)?;
+    let case = attrs.rename_all.as_deref().copied().unwrap_or(Case::Snake);
+
+    let from_str = attrs.from_str.is_some();
+    let mut seen = HashSet::new();
+    let variants = variants
+        .iter()
+        .map(|v| {
+            let variant_attrs = VariantAttrs::parse_attrs(ATTR_NAME, v)?;
+            let value = variant_attrs.rename.map_or_else(
+                || v.ident.to_case_string(case),
+                Spanning::into_inner,
+            );
+            if !from_str && syn::Ident::parse_any.parse_str(&value).is_err() {
+                return Err(syn::Error::new_spanned(
+                    &v.ident,
+                    format!(
+                        "`{value}` value is not a valid identifier, so \
+                         requires `#[{ATTR_NAME}(from_str)]` enum argument",
+                    ),
+                ));
+            }
+            if !seen.insert(value.clone()) {
+                return Err(syn::Error::new_spanned(
+                    &v.ident,
+                    format!("duplicated `{value}` value of variants"),
+                ));
+            }
+            Ok(Variant { ident: v.ident.clone(), value })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let definition = Definition {
+        ty: input.ident.clone(),
+        generics: input.generics.clone(),
+        from_str,
+        variants,
+    };
+    Ok(definition.into_token_stream())
+}
+
+/// Representation of a fieldless enum, used for code generation.
+#[derive(Debug)]
+struct Definition {
+    /// [`syn::Ident`] of this enum's type.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ty: syn::Ident,
+
+    /// [`syn::Generics`] of this enum's type.
+    generics: syn::Generics,
+
+    /// Indicator whether values may be also specified as string literals.
+    from_str: bool,
+
+    /// [`Variant`]s of this enum.
+    variants: Vec<Variant>,
+}
+
+impl Definition {
+    /// Generates code of all the implementations for this enum.
+    fn into_token_stream(self) -> TokenStream {
+        let ty = &self.ty;
+        let (impl_generics, ty_generics, where_clause) =
+            self.generics.split_for_impl();
+        let from_str = self.from_str;
+
+        let values = self.variants.iter().map(|v| &v.value);
+        let from_value_arms = self.variants.iter().map(|v| {
+            let (variant, value) = (&v.ident, &v.value);
+            quote! { #value => ::std::option::Option::Some(Self::#variant), }
+        });
+        let value_arms = self.variants.iter().map(|v| {
+            let (variant, value) = (&v.ident, &v.value);
+            quote! { Self::#variant => #value, }
+        });
+        let to_tokens_arms = self.variants.iter().map(|v| {
+            let (variant, value) = (&v.ident, &v.value);
+            let is_ident = syn::Ident::parse_any.parse_str(value).is_ok();
+            let tokens = if is_ident {
+                quote! {
+                    ::synthez::proc_macro2::Ident::new(
+                        #value,
+                        ::synthez::proc_macro2::Span::call_site(),
+                    )
+                }
+            } else {
+                quote! {
+                    ::synthez::syn::LitStr::new(
+                        #value,
+                        ::synthez::proc_macro2::Span::call_site(),
+                    )
+                }
+            };
+            quote! {
+                Self::#variant => {
+                    ::synthez::quote::ToTokens::to_tokens(&#tokens, out);
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::synthez::parse::IdentEnum
+             for #ty #ty_generics #where_clause
+            {
+                const VALUES: &'static [&'static str] = &[#( #values ),*];
+
+                const FROM_STR: bool = #from_str;
+
+                fn from_value(value: &str) -> ::std::option::Option<Self> {
+                    match value {
+                        #( #from_value_arms )*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+
+                fn value(&self) -> &'static str {
+                    match self {
+                        #( #value_arms )*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::synthez::syn::parse::Parse
+             for #ty #ty_generics #where_clause
+            {
+                fn parse(
+                    input: ::synthez::syn::parse::ParseStream<'_>,
+                ) -> ::synthez::syn::Result<Self> {
+                    <Self as ::synthez::parse::IdentEnum>::parse_spanned(input)
+                        .map(::synthez::Spanning::into_inner)
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::synthez::quote::ToTokens
+             for #ty #ty_generics #where_clause
+            {
+                fn to_tokens(
+                    &self,
+                    out: &mut ::synthez::proc_macro2::TokenStream,
+                ) {
+                    match self {
+                        #( #to_tokens_arms )*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::std::fmt::Display
+             for #ty #ty_generics #where_clause
+            {
+                fn fmt(
+                    &self,
+                    f: &mut ::std::fmt::Formatter<'_>,
+                ) -> ::std::fmt::Result {
+                    f.write_str(
+                        <Self as ::synthez::parse::IdentEnum>::value(self),
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Representation of a fieldless enum variant, used for code generation.
+#[derive(Debug)]
+struct Variant {
+    /// [`syn::Ident`] of this [`Variant`].
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    ident: syn::Ident,
+
+    /// Value representing this [`Variant`] in the parsed input.
+    value: String,
+}
+
+/// Representation of an `#[ident_enum]` attribute used along with a
+/// `#[derive(ParseIdentEnum)]` proc macro and placed on an enum.
+#[derive(Debug, Default)]
+struct EnumAttrs {
+    /// [`Case`] to convert the variants' names into for their values.
+    // #[parse(value)]
+    rename_all: Option<Spanning<Case>>,
+
+    /// Indicator whether values may be also specified as string literals.
+    // #[parse(ident)]
+    from_str: Option<syn::Ident>,
+}
+
+impl Parse for EnumAttrs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.fork().parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "rename_all" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated_with::<
+                        Spanning<Case>, token::Paren, token::Comma,
+                    >(parse_case)? {
+                        out.rename_all
+                            .try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                "from_str" => {
+                    out.from_str.try_merge::<kind::Ident, dedup::Unique>(
+                        input.parse_any_ident()?,
+                    )?;
+                }
+                name => {
+                    return Err(err::unknown_attr_arg(&ident, name));
+                }
+            }
+            if input.try_parse::<token::Comma>()?.is_none() && !input.is_empty()
+            {
+                return Err(err::expected_followed_by_comma(&ident));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl ParseAttrs for EnumAttrs {
+    fn try_merge(mut self, another: Self) -> syn::Result<Self> {
+        self.rename_all
+            .try_merge_self::<kind::Value, dedup::Unique>(another.rename_all)?;
+        self.from_str
+            .try_merge_self::<kind::Ident, dedup::Unique>(another.from_str)?;
+        Ok(self)
+    }
+}
+
+/// Representation of an `#[ident_enum]` attribute used along with a
+/// `#[derive(ParseIdentEnum)]` proc macro and placed on enum variants.
+#[derive(Debug, Default)]
+struct VariantAttrs {
+    /// Value to represent the variant with instead of its converted name.
+    // #[parse(value)]
+    rename: Option<Spanning<String>>,
+}
+
+impl Parse for VariantAttrs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.fork().parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "rename" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated_with::<
+                        Spanning<String>, token::Paren, token::Comma,
+                    >(parse_value)? {
+                        out.rename.try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                name => {
+                    return Err(err::unknown_attr_arg(&ident, name));
+                }
+            }
+            if input.try_parse::<token::Comma>()?.is_none() && !input.is_empty()
+            {
+                return Err(err::expected_followed_by_comma(&ident));
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl ParseAttrs for VariantAttrs {
+    fn try_merge(mut self, another: Self) -> syn::Result<Self> {
+        self.rename
+            .try_merge_self::<kind::Value, dedup::Unique>(another.rename)?;
+        Ok(self)
+    }
+}
+
+/// Parses a [`Case`] out of a string literal, along with its [`Span`].
+///
+/// [`Span`]: proc_macro2::Span
+fn parse_case(input: ParseStream<'_>) -> syn::Result<Spanning<Case>> {
+    let span = input.span();
+    Ok(Spanning::new(input.parse::<Case>()?, span))
+}
+
+/// Parses a variant's value out of an identifier or a string literal, along
+/// with its [`Span`].
+///
+/// [`Span`]: proc_macro2::Span
+fn parse_value(input: ParseStream<'_>) -> syn::Result<Spanning<String>> {
+    if input.peek(syn::LitStr) {
+        let lit = input.parse::<syn::LitStr>()?;
+        Ok(Spanning::new(lit.value(), lit.span()))
+    } else {
+        let ident = input.parse_any_ident()?;
+        Ok(Spanning::new(ident.unraw().to_string(), &ident))
+    }
+}
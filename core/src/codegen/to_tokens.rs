@@ -1,7 +1,7 @@
 //! `#[derive(ToTokens)]` proc macro implementation.
 
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{ToTokens as _, quote};
 use syn::{
     parse::{Parse, ParseStream},
     token,
@@ -9,8 +9,9 @@ use syn::{
 
 use crate::{
     ParseAttrs,
+    ext::{FieldBinding, Fields as _},
     parse::{
-        attrs::{dedup, field::TryMerge as _, kind},
+        attrs::{dedup, field::TryMerge as _, filter_by_name, kind},
         err,
         ext::ParseBuffer as _,
     },
@@ -28,25 +29,60 @@ const ATTR_NAME: &str = "to_tokens";
 ///
 /// - If the proc macro isn't applied to a struct or an enum.
 /// - If parsing `#[to_tokens]` helper attribute fails.
+/// - If a struct has no `#[to_tokens(append(...))]` attribute.
+/// - If enum variants have `#[to_tokens]` attributes along with the top-level
+///   `#[to_tokens(append(...))]` one.
 pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
-    if !matches!(&input.data, syn::Data::Enum(_) | syn::Data::Struct(_)) {
-        return Err(syn::Error::new_spanned(
-            input,
-            format!("only structs and enums can derive {TRAIT_NAME}"),
-        ));
-    }
-
     let attrs = Attrs::parse_attrs(ATTR_NAME, input)?;
 
-    let ty = &input.ident;
-    let (impl_generics, ty_generics, where_clause) =
-        input.generics.split_for_impl();
-
-    let impls = attrs.append.iter().map(|method| {
+    let out = syn::Ident::new("out", Span::mixed_site());
+    let appends = attrs.append.iter().map(|method| {
         quote! {
-            ::synthez::quote::ToTokens::to_tokens(&self.#method(), out);
+            ::synthez::quote::ToTokens::to_tokens(&self.#method(), #out);
         }
     });
+    let body = match &input.data {
+        syn::Data::Struct(_) if attrs.append.is_empty() => {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!(
+                    "`#[{ATTR_NAME}(append(<function>))]` attribute is \
+                     expected",
+                ),
+            ));
+        }
+        syn::Data::Enum(data) if attrs.append.is_empty() => {
+            gen_variants_match(data, &out)?
+        }
+        syn::Data::Enum(data) => {
+            if let Some(v) = data
+                .variants
+                .iter()
+                .find(|v| filter_by_name(ATTR_NAME, &v.attrs).next().is_some())
+            {
+                return Err(syn::Error::new_spanned(
+                    &v.ident,
+                    format!(
+                        "`#[{ATTR_NAME}]` attribute on enum variants is not \
+                         allowed along with the top-level \
+                         `#[{ATTR_NAME}(append(...))]` one",
+                    ),
+                ));
+            }
+            quote! { #( #appends )* }
+        }
+        syn::Data::Struct(_) => quote! { #( #appends )* },
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                format!("only structs and enums can derive {TRAIT_NAME}"),
+            ));
+        }
+    };
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
 
     Ok(quote! {
         #[automatically_derived]
@@ -55,14 +91,117 @@ pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
         {
             fn to_tokens(
                 &self,
-                out: &mut ::synthez::proc_macro2::TokenStream,
+                #out: &mut ::synthez::proc_macro2::TokenStream,
             ) {
-                #( #impls )*
+                #body
             }
         }
     })
 }
 
+/// Generates a `match` over the variants of the given enum, emitting tokens
+/// of each variant according to its [`VariantAttrs`] into the `out`
+/// [`TokenStream`].
+///
+/// # Errors
+///
+/// - If parsing `#[to_tokens]` helper attribute of a variant fails.
+/// - If a variant's `#[to_tokens(fields(...))]` refers to unknown fields.
+fn gen_variants_match(
+    data: &syn::DataEnum,
+    out: &syn::Ident,
+) -> syn::Result<TokenStream> {
+    if data.variants.is_empty() {
+        return Ok(quote! { match *self {} });
+    }
+
+    let arms = data
+        .variants
+        .iter()
+        .map(|v| {
+            let attrs = VariantAttrs::parse_attrs(ATTR_NAME, v)?;
+            let bindings = v.fields.bindings();
+
+            let emitted = if attrs.fields.is_empty() && attrs.append.is_empty()
+            {
+                bindings.iter().collect::<Vec<_>>()
+            } else {
+                attrs
+                    .fields
+                    .iter()
+                    .map(|m| {
+                        bindings.iter().find(|b| b.member == *m).ok_or_else(
+                            || {
+                                syn::Error::new_spanned(
+                                    m,
+                                    "unknown field of the enum variant",
+                                )
+                            },
+                        )
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?
+            };
+
+            let variant = &v.ident;
+            let path = quote! { Self::#variant };
+            let is_emitted = |b: &FieldBinding<'_>| {
+                emitted.iter().any(|e| e.index == b.index)
+            };
+            let pattern = match &v.fields {
+                syn::Fields::Named(_) => {
+                    let fields = bindings.iter().map(|b| {
+                        let member = &b.member;
+                        if is_emitted(b) {
+                            member.to_token_stream()
+                        } else {
+                            quote! { #member: _ }
+                        }
+                    });
+                    quote! { #path { #( #fields ),* } }
+                }
+                syn::Fields::Unnamed(_) => {
+                    let fields = bindings.iter().map(|b| {
+                        if is_emitted(b) {
+                            b.binding.to_token_stream()
+                        } else {
+                            quote! { _ }
+                        }
+                    });
+                    quote! { #path( #( #fields ),* ) }
+                }
+                syn::Fields::Unit => path,
+            };
+
+            let fields = emitted.iter().map(|b| {
+                let binding = &b.binding;
+                quote! {
+                    ::synthez::quote::ToTokens::to_tokens(#binding, #out);
+                }
+            });
+            let appends = attrs.append.iter().map(|method| {
+                quote! {
+                    ::synthez::quote::ToTokens::to_tokens(
+                        &self.#method(),
+                        #out,
+                    );
+                }
+            });
+            Ok(quote! {
+                #pattern => {
+                    #( #fields )*
+                    #( #appends )*
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        match self {
+            #( #arms )*
+        }
+    })
+}
+
 /// Representation of a `#[to_tokens]` attribute used along with a
 /// `#[derive(ToTokens)]` proc macro on a top-level definition.
 #[derive(Debug, Default)]
@@ -107,17 +246,68 @@ impl ParseAttrs for Attrs {
             .try_merge_self::<kind::Value, dedup::Unique>(another.append)?;
         Ok(self)
     }
+}
 
-    fn validate(&self, attr_name: &str, item_span: Span) -> syn::Result<()> {
-        if self.append.is_empty() {
-            return Err(syn::Error::new(
-                item_span,
-                format!(
-                    "`#[{attr_name}(append(<function>))]` attribute is \
-                     expected",
-                ),
-            ));
+/// Representation of a `#[to_tokens]` attribute used along with a
+/// `#[derive(ToTokens)]` proc macro on enum variants.
+#[derive(Debug, Default)]
+struct VariantAttrs {
+    /// Fields of the variant to be emitted in the generated [`ToTokens`]
+    /// implementation, in the specified order.
+    ///
+    /// [`ToTokens`]: quote::ToTokens
+    // #[parse(value)]
+    fields: Vec<syn::Member>,
+
+    /// Methods to be called in the generated [`ToTokens`] implementation after
+    /// emitting the [`VariantAttrs::fields`].
+    ///
+    /// [`ToTokens`]: quote::ToTokens
+    // #[parse(value)]
+    append: Vec<syn::Ident>,
+}
+
+impl Parse for VariantAttrs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.fork().parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "fields" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated::<
+                        syn::Member, token::Paren, token::Comma,
+                    >()? {
+                        out.fields.try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                "append" => {
+                    input.skip_any_ident()?;
+                    for v in input.parse_eq_or_wrapped_and_punctuated::<
+                        syn::Ident, token::Paren, token::Comma,
+                    >()? {
+                        out.append.try_merge::<kind::Value, dedup::Unique>(v)?;
+                    }
+                }
+                name => {
+                    return Err(err::unknown_attr_arg(&ident, name));
+                }
+            }
+            if input.try_parse::<token::Comma>()?.is_none() && !input.is_empty()
+            {
+                return Err(err::expected_followed_by_comma(&ident));
+            }
         }
-        Ok(())
+        Ok(out)
+    }
+}
+
+impl ParseAttrs for VariantAttrs {
+    fn try_merge(mut self, another: Self) -> syn::Result<Self> {
+        self.fields
+            .try_merge_self::<kind::Value, dedup::Unique>(another.fields)?;
+        self.append
+            .try_merge_self::<kind::Value, dedup::Unique>(another.append)?;
+        Ok(self)
     }
 }
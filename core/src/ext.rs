@@ -1,9 +1,17 @@
 //! Extensions for [`syn`] types.
 
-use proc_macro2::Span;
+use std::{borrow::Borrow, collections::HashSet};
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{ToTokens, quote};
 use sealed::sealed;
 use syn::{punctuated::Punctuated, token};
 
+use crate::{
+    case::{self, Case},
+    ident,
+};
+
 /// Extension of a [`syn::Data`].
 #[sealed]
 pub trait Data {
@@ -52,6 +60,76 @@ pub trait Data {
     fn unnamed_fields_ref(
         &self,
     ) -> syn::Result<&Punctuated<syn::Field, token::Comma>>;
+
+    /// Parses [`syn::Fields`] of any shape (including [`syn::Fields::Unit`])
+    /// from this consumed [`syn::Data::Struct`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`syn::Data`] is not a [`syn::Data::Struct`].
+    fn fields(self) -> syn::Result<syn::Fields>;
+
+    /// Parses [`syn::Fields`] of any shape (including [`syn::Fields::Unit`])
+    /// from this borrowed [`syn::Data::Struct`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`syn::Data`] is not a [`syn::Data::Struct`].
+    fn fields_ref(&self) -> syn::Result<&syn::Fields>;
+
+    /// Parses [`syn::Variant`]s from this consumed [`syn::Data::Enum`] and
+    /// returns owning iterator over them.
+    ///
+    /// # Errors
+    ///
+    /// If this [`syn::Data`] is not a [`syn::Data::Enum`].
+    fn variants(self) -> syn::Result<Punctuated<syn::Variant, token::Comma>>;
+
+    /// Parses [`syn::Variant`]s from this borrowed [`syn::Data::Enum`] and
+    /// returns referencing iterator over them.
+    ///
+    /// # Errors
+    ///
+    /// If this [`syn::Data`] is not a [`syn::Data::Enum`].
+    fn variants_ref(
+        &self,
+    ) -> syn::Result<&Punctuated<syn::Variant, token::Comma>>;
+
+    /// Parses [`syn::Variant`]s from this borrowed [`syn::Data::Enum`],
+    /// ensuring all of them are [`syn::Fields::Unit`] (as in C-like enums),
+    /// and returns referencing iterator over them.
+    ///
+    /// # Errors
+    ///
+    /// - If this [`syn::Data`] is not a [`syn::Data::Enum`].
+    /// - If any of [`syn::Variant`]s has fields (pointing to every such
+    ///   [`syn::Variant`]).
+    fn unit_variants_only(
+        &self,
+    ) -> syn::Result<&Punctuated<syn::Variant, token::Comma>>;
+
+    /// Parses [`syn::Variant`]s from this borrowed [`syn::Data::Enum`],
+    /// ensuring all of them have exactly one [`syn::Fields::Unnamed`] field,
+    /// and returns them along with this single [`syn::Field`].
+    ///
+    /// # Errors
+    ///
+    /// - If this [`syn::Data`] is not a [`syn::Data::Enum`].
+    /// - If any of [`syn::Variant`]s is not a newtype one (pointing to every
+    ///   such [`syn::Variant`]).
+    fn newtype_variants(
+        &self,
+    ) -> syn::Result<Vec<(&syn::Variant, &syn::Field)>>;
+
+    /// Returns paths to this borrowed [`syn::Data`] (`Self` for a
+    /// [`syn::Data::Struct`], or `Self::Variant` for every [`syn::Variant`] of
+    /// a [`syn::Data::Enum`]) along with their [`syn::Fields`], ready to be
+    /// used in [`Fields::destructuring_pattern()`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`syn::Data`] is a [`syn::Data::Union`].
+    fn self_paths(&self) -> syn::Result<Vec<(syn::Path, &syn::Fields)>>;
 }
 
 #[sealed]
@@ -149,6 +227,855 @@ impl Data for syn::Data {
             )),
         }
     }
+
+    fn fields(self) -> syn::Result<syn::Fields> {
+        match self {
+            Self::Struct(data) => Ok(data.fields),
+            Self::Enum(data) => Err(syn::Error::new_spanned(
+                data.enum_token,
+                "expected struct only",
+            )),
+            Self::Union(data) => Err(syn::Error::new_spanned(
+                data.union_token,
+                "expected struct only",
+            )),
+        }
+    }
+
+    fn fields_ref(&self) -> syn::Result<&syn::Fields> {
+        match self {
+            Self::Struct(data) => Ok(&data.fields),
+            Self::Enum(data) => Err(syn::Error::new_spanned(
+                data.enum_token,
+                "expected struct only",
+            )),
+            Self::Union(data) => Err(syn::Error::new_spanned(
+                data.union_token,
+                "expected struct only",
+            )),
+        }
+    }
+
+    fn variants(self) -> syn::Result<Punctuated<syn::Variant, token::Comma>> {
+        match self {
+            Self::Enum(data) => Ok(data.variants),
+            Self::Struct(data) => Err(syn::Error::new_spanned(
+                data.struct_token,
+                "expected enum only",
+            )),
+            Self::Union(data) => Err(syn::Error::new_spanned(
+                data.union_token,
+                "expected enum only",
+            )),
+        }
+    }
+
+    fn variants_ref(
+        &self,
+    ) -> syn::Result<&Punctuated<syn::Variant, token::Comma>> {
+        match self {
+            Self::Enum(data) => Ok(&data.variants),
+            Self::Struct(data) => Err(syn::Error::new_spanned(
+                data.struct_token,
+                "expected enum only",
+            )),
+            Self::Union(data) => Err(syn::Error::new_spanned(
+                data.union_token,
+                "expected enum only",
+            )),
+        }
+    }
+
+    fn unit_variants_only(
+        &self,
+    ) -> syn::Result<&Punctuated<syn::Variant, token::Comma>> {
+        let variants = self.variants_ref()?;
+        variants
+            .iter()
+            .filter(|v| !matches!(v.fields, syn::Fields::Unit))
+            .map(|v| syn::Error::new_spanned(v, "expected unit variant only"))
+            .reduce(|mut acc, err| {
+                acc.combine(err);
+                acc
+            })
+            .map_or(Ok(variants), Err)
+    }
+
+    fn newtype_variants(
+        &self,
+    ) -> syn::Result<Vec<(&syn::Variant, &syn::Field)>> {
+        let variants = self.variants_ref()?;
+        variants
+            .iter()
+            .filter(|v| single_unnamed_field(&v.fields).is_none())
+            .map(|v| {
+                syn::Error::new_spanned(
+                    v,
+                    "expected variant with a single unnamed field only",
+                )
+            })
+            .reduce(|mut acc, err| {
+                acc.combine(err);
+                acc
+            })
+            .map_or_else(
+                || {
+                    Ok(variants
+                        .iter()
+                        .filter_map(|v| {
+                            single_unnamed_field(&v.fields).map(|f| (v, f))
+                        })
+                        .collect())
+                },
+                Err,
+            )
+    }
+
+    fn self_paths(&self) -> syn::Result<Vec<(syn::Path, &syn::Fields)>> {
+        match self {
+            Self::Struct(data) => {
+                Ok(vec![(syn::parse_quote! { Self }, &data.fields)])
+            }
+            Self::Enum(data) => Ok(data
+                .variants
+                .iter()
+                .map(|v| {
+                    let variant = &v.ident;
+                    (syn::parse_quote! { Self::#variant }, &v.fields)
+                })
+                .collect()),
+            Self::Union(data) => Err(syn::Error::new_spanned(
+                data.union_token,
+                "expected struct or enum only",
+            )),
+        }
+    }
+}
+
+/// Returns the only field of the given [`syn::Fields::Unnamed`], if any.
+fn single_unnamed_field(fields: &syn::Fields) -> Option<&syn::Field> {
+    match fields {
+        syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => f.unnamed.first(),
+        syn::Fields::Unit | syn::Fields::Named(_) | syn::Fields::Unnamed(_) => {
+            None
+        }
+    }
+}
+
+/// Extension of a [`syn::Fields`].
+///
+/// ```rust
+/// # use synthez::{DataExt as _, FieldsExt as _, quote::quote, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     enum Foo {
+///         Bar { a: u8, b: u8 },
+///         Baz(u8, u8),
+///         Qux,
+///     }
+/// };
+///
+/// let arms = input.data.self_paths().unwrap().into_iter().map(|(path, f)| {
+///     let pat = f.destructuring_pattern(path);
+///     let vals = f.bindings().into_iter().map(|f| f.binding);
+///     quote! { #pat => vec![#( #vals ),*], }
+/// });
+/// let expected = quote! {
+///     Self::Bar { a, b } => vec![a, b],
+///     Self::Baz(f0, f1) => vec![f0, f1],
+///     Self::Qux => vec![],
+/// };
+/// assert_eq!(quote! { #( #arms )* }.to_string(), expected.to_string());
+/// ```
+#[sealed]
+pub trait Fields {
+    /// Returns [`FieldBinding`]s of all these [`syn::Fields`], uniformly
+    /// describing both named and unnamed ones.
+    #[must_use]
+    fn bindings(&self) -> Vec<FieldBinding<'_>>;
+
+    /// Builds a pattern destructuring these [`syn::Fields`] of the given
+    /// `path` into [`FieldBinding::binding`]s (like `Self { a, b }`,
+    /// `Self(f0, f1)` or `Self::Variant`).
+    #[must_use]
+    fn destructuring_pattern<P: ToTokens>(&self, path: P) -> TokenStream;
+
+    /// Builds a pattern matching the given `path` with these [`syn::Fields`],
+    /// while ignoring all of them (like `Self { .. }`, `Self(..)` or
+    /// `Self::Variant`).
+    #[must_use]
+    fn ignoring_pattern<P: ToTokens>(&self, path: P) -> TokenStream;
+}
+
+#[sealed]
+impl Fields for syn::Fields {
+    fn bindings(&self) -> Vec<FieldBinding<'_>> {
+        self.iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let (member, binding) = field.ident.as_ref().map_or_else(
+                    || {
+                        let binding = <syn::Ident as Ident>::new_on_mixed_site(
+                            &format!("f{index}"),
+                        );
+                        (index.into(), binding)
+                    },
+                    |ident| (syn::Member::Named(ident.clone()), ident.clone()),
+                );
+                FieldBinding { field, index, member, binding }
+            })
+            .collect()
+    }
+
+    fn destructuring_pattern<P: ToTokens>(&self, path: P) -> TokenStream {
+        let bindings = self.bindings().into_iter().map(|f| f.binding);
+        match self {
+            Self::Named(_) => quote! { #path { #( #bindings ),* } },
+            Self::Unnamed(_) => quote! { #path( #( #bindings ),* ) },
+            Self::Unit => path.into_token_stream(),
+        }
+    }
+
+    fn ignoring_pattern<P: ToTokens>(&self, path: P) -> TokenStream {
+        match self {
+            Self::Named(_) => quote! { #path { .. } },
+            Self::Unnamed(_) => quote! { #path(..) },
+            Self::Unit => path.into_token_stream(),
+        }
+    }
+}
+
+/// Uniform description of a [`syn::Field`], either named or unnamed one.
+#[derive(Clone, Debug)]
+pub struct FieldBinding<'f> {
+    /// Described [`syn::Field`] itself.
+    pub field: &'f syn::Field,
+
+    /// Index of the described [`syn::Field`] in its [`syn::Fields`].
+    pub index: usize,
+
+    /// [`syn::Member`] to access the described [`syn::Field`] with (like
+    /// `self.name` or `self.0`).
+    pub member: syn::Member,
+
+    /// [`syn::Ident`] to bind the described [`syn::Field`] to in patterns:
+    /// its name for a named [`syn::Field`], or `f<index>` for an unnamed one.
+    ///
+    /// Bindings of unnamed [`syn::Field`]s are created with a
+    /// [`Span::mixed_site`], so they never clash with user-provided
+    /// identifiers.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    pub binding: syn::Ident,
+}
+
+impl FieldBinding<'_> {
+    /// Returns [`syn::Attribute`]s of the described [`syn::Field`].
+    #[must_use]
+    pub fn attrs(&self) -> &[syn::Attribute] {
+        &self.field.attrs
+    }
+}
+
+/// Extension of a [`syn::Generics`].
+///
+/// All the methods return a modified copy of these [`syn::Generics`], ready to
+/// be [`split_for_impl()`]. Note, that the [`syn::TypeGenerics`] should still
+/// be taken from the original [`syn::Generics`], if the modified ones contain
+/// new parameters, not present in the type definition.
+///
+/// ```rust
+/// # use synthez::{DataExt as _, GenericsExt as _, ident, quote::quote, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     struct Foo<'a, T, U> where T: Clone {
+///         foo: &'a T,
+///         bar: Vec<u8>,
+///     }
+/// };
+/// let mut idents = ident::Factory::new(&input);
+///
+/// let tys = input
+///     .data
+///     .named_fields_ref()
+///     .into_iter()
+///     .flatten()
+///     .map(|f| &f.ty);
+/// let generics = input
+///     .generics
+///     .with_bound_for_used(&syn::parse_quote!(Send), tys);
+/// let (generics, lt) = generics.with_fresh_lifetime(&mut idents, "a");
+/// let (impl_gens, _, where_clause) = generics.split_for_impl();
+///
+/// assert_eq!(
+///     quote! { impl #impl_gens Foo #where_clause }.to_string(),
+///     quote! { impl<'a_1, 'a, T: Send, U> Foo where T: Clone }.to_string(),
+/// );
+/// assert_eq!(lt.to_string(), "'a_1");
+/// ```
+///
+/// [`split_for_impl()`]: syn::Generics::split_for_impl
+#[sealed]
+pub trait Generics {
+    /// Adds the given `bound` to every type parameter of these
+    /// [`syn::Generics`].
+    #[must_use]
+    fn with_bound(&self, bound: &syn::TypeParamBound) -> syn::Generics;
+
+    /// Adds the given `bound` only to the type parameters of these
+    /// [`syn::Generics`] used in the provided `types` (like field
+    /// [`syn::Type`]s), as detected by [`Generics::usage_in()`].
+    ///
+    /// So, path-qualified types named as a type parameter (like `other::T` or
+    /// `<U as Tr>::T`) are not considered as its usage.
+    ///
+    /// ```rust
+    /// # use synthez::{GenericsExt as _, quote::ToTokens as _, syn};
+    /// #
+    /// let generics: syn::Generics = syn::parse_quote! { <T, U> };
+    /// let other: syn::Type = syn::parse_quote! { other::T };
+    /// let assoc: syn::Type = syn::parse_quote! { <U as Tr>::T };
+    ///
+    /// let bound = syn::parse_quote!(Send);
+    /// let generics = generics.with_bound_for_used(&bound, [&other, &assoc]);
+    /// assert_eq!(generics.to_token_stream().to_string(), "< T , U : Send >");
+    /// ```
+    #[must_use]
+    fn with_bound_for_used<T: Borrow<syn::Type>>(
+        &self,
+        bound: &syn::TypeParamBound,
+        types: impl IntoIterator<Item = T>,
+    ) -> syn::Generics;
+
+    /// Adds a fresh lifetime parameter produced by the given [`ident::Factory`]
+    /// out of the `base` name (with or without a leading `'`) to these
+    /// [`syn::Generics`], and returns it.
+    ///
+    /// The [`ident::Factory`] is additionally made aware of all the identifiers
+    /// of these [`syn::Generics`], so the fresh lifetime doesn't collide with
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// If the given `base` name is not a valid identifier.
+    #[must_use]
+    fn with_fresh_lifetime(
+        &self,
+        idents: &mut ident::Factory,
+        base: &str,
+    ) -> (syn::Generics, syn::Lifetime);
+
+    /// Adds a fresh type parameter produced by the given [`ident::Factory`] out
+    /// of the `base` name to these [`syn::Generics`], and returns it.
+    ///
+    /// The [`ident::Factory`] is additionally made aware of all the identifiers
+    /// of these [`syn::Generics`], so the fresh type parameter doesn't collide
+    /// with them.
+    ///
+    /// The fresh type parameter is placed after all the lifetimes and before
+    /// the first defaulted parameter (if any), so the resulting
+    /// [`syn::Generics`] remain valid.
+    ///
+    /// # Panics
+    ///
+    /// If the given `base` name is not a valid identifier.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn with_fresh_type_param(
+        &self,
+        idents: &mut ident::Factory,
+        base: &str,
+    ) -> (syn::Generics, syn::Ident);
+
+    /// Appends the given `predicates` to the [`syn::WhereClause`] of these
+    /// [`syn::Generics`], creating it if absent.
+    #[must_use]
+    fn with_predicates(
+        &self,
+        predicates: impl IntoIterator<Item = syn::WherePredicate>,
+    ) -> syn::Generics;
+
+    /// Merges the given [`syn::WhereClause`] into the one of these
+    /// [`syn::Generics`].
+    #[must_use]
+    fn with_where_clause(&self, other: &syn::WhereClause) -> syn::Generics;
+
+    /// Detects which parameters of these [`syn::Generics`] are used in the
+    /// given [`syn::Type`] (like a field one), along with associated type
+    /// projections on them (like `T::Item`), preserving the [`Span`]s of every
+    /// usage.
+    ///
+    /// Lifetimes introduced by `for<..>` binders (like in `for<'a> fn(&'a T)`)
+    /// are not considered as usages of the same-named lifetime parameters.
+    #[must_use]
+    fn usage_in(&self, ty: &syn::Type) -> GenericsUsage;
+}
+
+#[sealed]
+impl Generics for syn::Generics {
+    fn with_bound(&self, bound: &syn::TypeParamBound) -> Self {
+        let mut generics = self.clone();
+        for param in generics.type_params_mut() {
+            param.bounds.push(bound.clone());
+        }
+        generics
+    }
+
+    fn with_bound_for_used<T: Borrow<syn::Type>>(
+        &self,
+        bound: &syn::TypeParamBound,
+        types: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let used = types
+            .into_iter()
+            .flat_map(|ty| self.usage_in(ty.borrow()).type_params)
+            .collect::<HashSet<_>>();
+
+        let mut generics = self.clone();
+        for param in generics.type_params_mut() {
+            if used.contains(&param.ident) {
+                param.bounds.push(bound.clone());
+            }
+        }
+        generics
+    }
+
+    fn with_fresh_lifetime(
+        &self,
+        idents: &mut ident::Factory,
+        base: &str,
+    ) -> (Self, syn::Lifetime) {
+        idents.reserve(self);
+        let ident = idents.fresh(base.strip_prefix('\'').unwrap_or(base));
+        let lifetime = syn::Lifetime::new(&format!("'{ident}"), ident.span());
+
+        let mut generics = self.clone();
+        generics.params.insert(
+            0,
+            syn::GenericParam::Lifetime(syn::LifetimeParam::new(
+                lifetime.clone(),
+            )),
+        );
+        (generics, lifetime)
+    }
+
+    fn with_fresh_type_param(
+        &self,
+        idents: &mut ident::Factory,
+        base: &str,
+    ) -> (Self, syn::Ident) {
+        idents.reserve(self);
+        let ident = idents.fresh(base);
+
+        // Defaulted parameters must go last, so the fresh one is inserted
+        // right before them.
+        let pos = self
+            .params
+            .iter()
+            .position(|p| match p {
+                syn::GenericParam::Type(p) => p.default.is_some(),
+                syn::GenericParam::Const(p) => p.default.is_some(),
+                syn::GenericParam::Lifetime(_) => false,
+            })
+            .unwrap_or(self.params.len());
+
+        let mut generics = self.clone();
+        generics.params.insert(
+            pos,
+            syn::GenericParam::Type(syn::TypeParam::from(ident.clone())),
+        );
+        (generics, ident)
+    }
+
+    fn with_predicates(
+        &self,
+        predicates: impl IntoIterator<Item = syn::WherePredicate>,
+    ) -> Self {
+        let mut generics = self.clone();
+        generics.make_where_clause().predicates.extend(predicates);
+        generics
+    }
+
+    fn with_where_clause(&self, other: &syn::WhereClause) -> Self {
+        self.with_predicates(other.predicates.iter().cloned())
+    }
+
+    fn usage_in(&self, ty: &syn::Type) -> GenericsUsage {
+        let mut visitor = UsageVisitor {
+            type_params: self.type_params().map(|p| &p.ident).collect(),
+            const_params: self.const_params().map(|p| &p.ident).collect(),
+            lifetimes: self.lifetimes().map(|p| &p.lifetime).collect(),
+            bound_lifetimes: Vec::new(),
+            usage: GenericsUsage::default(),
+        };
+        visitor.visit_type(ty);
+        visitor.usage
+    }
+}
+
+/// Usage of [`syn::Generics`] parameters detected by
+/// [`Generics::usage_in()`].
+///
+/// Every usage is recorded separately, in the order of appearance, preserving
+/// its [`Span`].
+#[derive(Clone, Debug, Default)]
+pub struct GenericsUsage {
+    /// Used type parameters.
+    pub type_params: Vec<syn::Ident>,
+
+    /// Used const parameters.
+    pub const_params: Vec<syn::Ident>,
+
+    /// Used lifetime parameters.
+    pub lifetimes: Vec<syn::Lifetime>,
+
+    /// Used associated type projections on type parameters (like `T::Item` or
+    /// `<T as Iterator>::Item`).
+    pub projections: Vec<syn::TypePath>,
+}
+
+impl GenericsUsage {
+    /// Indicates whether no [`syn::Generics`] parameters are used at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.type_params.is_empty()
+            && self.const_params.is_empty()
+            && self.lifetimes.is_empty()
+    }
+}
+
+/// Visitor of a [`syn::Type`] collecting [`GenericsUsage`].
+struct UsageVisitor<'g> {
+    /// Type parameters to look for.
+    type_params: HashSet<&'g syn::Ident>,
+
+    /// Const parameters to look for.
+    const_params: HashSet<&'g syn::Ident>,
+
+    /// Lifetime parameters to look for.
+    lifetimes: HashSet<&'g syn::Lifetime>,
+
+    /// Lifetimes introduced by the currently visited `for<..>` binders, which
+    /// shadow the lifetime parameters.
+    bound_lifetimes: Vec<syn::Lifetime>,
+
+    /// Collected [`GenericsUsage`].
+    usage: GenericsUsage,
+}
+
+impl UsageVisitor<'_> {
+    /// Visits the given [`syn::Type`] recursively.
+    fn visit_type(&mut self, ty: &syn::Type) {
+        match ty {
+            syn::Type::Array(arr) => {
+                self.visit_type(&arr.elem);
+                self.visit_tokens(arr.len.to_token_stream());
+            }
+            syn::Type::FnPtr(f) => {
+                self.within_binder(f.lifetimes.as_ref(), |this| {
+                    for input in &f.inputs {
+                        this.visit_type(&input.ty);
+                    }
+                    this.visit_return_type(&f.output);
+                });
+            }
+            syn::Type::Group(g) => self.visit_type(&g.elem),
+            syn::Type::ImplTrait(t) => self.visit_bounds(&t.bounds),
+            syn::Type::Paren(p) => self.visit_type(&p.elem),
+            syn::Type::Path(p) => self.visit_type_path(p),
+            syn::Type::Ptr(p) => self.visit_type(&p.elem),
+            syn::Type::Reference(r) => {
+                if let Some(lt) = &r.lifetime {
+                    self.visit_lifetime(lt);
+                }
+                self.visit_type(&r.elem);
+            }
+            syn::Type::Slice(s) => self.visit_type(&s.elem),
+            syn::Type::TraitObject(t) => self.visit_bounds(&t.bounds),
+            syn::Type::Tuple(t) => {
+                for elem in &t.elems {
+                    self.visit_type(elem);
+                }
+            }
+            syn::Type::Macro(m) => self.visit_tokens(m.mac.tokens.clone()),
+            syn::Type::Verbatim(tokens) => self.visit_tokens(tokens.clone()),
+            syn::Type::Infer(_) | syn::Type::Never(_) => {}
+            _ => self.visit_tokens(ty.to_token_stream()),
+        }
+    }
+
+    /// Visits the given [`syn::TypePath`] recursively, detecting type
+    /// parameters and associated type projections on them.
+    fn visit_type_path(&mut self, ty: &syn::TypePath) {
+        if let Some(qself) = &ty.qself {
+            if self.is_type_param(&qself.ty) {
+                self.usage.projections.push(ty.clone());
+            }
+            self.visit_type(&qself.ty);
+        } else if ty.path.leading_colon.is_none() {
+            if let Some(first) =
+                ty.path.segments.first().filter(|s| s.arguments.is_none())
+            {
+                if self.type_params.contains(&first.ident) {
+                    self.usage.type_params.push(first.ident.clone());
+                    if ty.path.segments.len() > 1 {
+                        self.usage.projections.push(ty.clone());
+                    }
+                } else if ty.path.segments.len() == 1
+                    && self.const_params.contains(&first.ident)
+                {
+                    self.usage.const_params.push(first.ident.clone());
+                }
+            }
+        }
+        self.visit_path_args(&ty.path);
+    }
+
+    /// Visits generic arguments of all the segments of the given [`syn::Path`].
+    fn visit_path_args(&mut self, path: &syn::Path) {
+        for segment in &path.segments {
+            match &segment.arguments {
+                syn::PathArguments::None => {}
+                syn::PathArguments::AngleBracketed(args) => {
+                    for arg in &args.args {
+                        self.visit_generic_arg(arg);
+                    }
+                }
+                syn::PathArguments::Parenthesized(args) => {
+                    for input in &args.inputs {
+                        self.visit_type(&input.ty);
+                    }
+                    self.visit_return_type(&args.output);
+                }
+            }
+        }
+    }
+
+    /// Visits the given [`syn::GenericArgument`] recursively.
+    #[expect( // intentional
+        clippy::wildcard_enum_match_arm,
+        reason = "the rest are opaque to the visitor"
+    )]
+    fn visit_generic_arg(&mut self, arg: &syn::GenericArgument) {
+        match arg {
+            syn::GenericArgument::Lifetime(lt) => self.visit_lifetime(lt),
+            syn::GenericArgument::Type(ty) => self.visit_type(ty),
+            syn::GenericArgument::AssocType(assoc) => {
+                self.visit_type(&assoc.ty);
+            }
+            syn::GenericArgument::Constraint(c) => self.visit_bounds(&c.bounds),
+            _ => self.visit_tokens(arg.to_token_stream()),
+        }
+    }
+
+    /// Visits the given [`syn::ReturnType`] recursively.
+    fn visit_return_type(&mut self, ret: &syn::ReturnType) {
+        if let syn::ReturnType::Type(_, ty) = ret {
+            self.visit_type(ty);
+        }
+    }
+
+    /// Visits the given [`syn::TypeParamBound`]s recursively.
+    #[expect( // intentional
+        clippy::wildcard_enum_match_arm,
+        reason = "the rest are opaque to the visitor"
+    )]
+    fn visit_bounds(
+        &mut self,
+        bounds: &Punctuated<syn::TypeParamBound, token::Plus>,
+    ) {
+        for bound in bounds {
+            match bound {
+                syn::TypeParamBound::Trait(t) => {
+                    self.within_binder(t.lifetimes.as_ref(), |this| {
+                        this.visit_path_args(&t.path);
+                    });
+                }
+                syn::TypeParamBound::Lifetime(lt) => self.visit_lifetime(lt),
+                _ => self.visit_tokens(bound.to_token_stream()),
+            }
+        }
+    }
+
+    /// Runs the given visiting function in the scope of the given `for<..>`
+    /// binder, so the lifetimes it introduces aren't recorded as parameters.
+    #[expect( // intentional
+        clippy::wildcard_enum_match_arm,
+        reason = "only lifetimes are of interest"
+    )]
+    fn within_binder(
+        &mut self,
+        binder: Option<&syn::BoundLifetimes>,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let outer_len = self.bound_lifetimes.len();
+        if let Some(binder) = binder {
+            self.bound_lifetimes.extend(binder.lifetimes.iter().filter_map(
+                |param| match param {
+                    syn::GenericParam::Lifetime(p) => Some(p.lifetime.clone()),
+                    _ => None,
+                },
+            ));
+        }
+        visit(self);
+        self.bound_lifetimes.truncate(outer_len);
+    }
+
+    /// Records the given [`syn::Lifetime`], if it's a parameter not shadowed
+    /// by any `for<..>` binder.
+    fn visit_lifetime(&mut self, lt: &syn::Lifetime) {
+        if self.lifetimes.contains(lt) && !self.bound_lifetimes.contains(lt) {
+            self.usage.lifetimes.push(lt.clone());
+        }
+    }
+
+    /// Visits the given opaque [`TokenStream`] (like a macro or an expression),
+    /// conservatively recording every type or const parameter mentioned in it.
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Ident(ident) => {
+                    if self.type_params.contains(&ident) {
+                        self.usage.type_params.push(ident);
+                    } else if self.const_params.contains(&ident) {
+                        self.usage.const_params.push(ident);
+                    }
+                }
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+            }
+        }
+    }
+
+    /// Checks whether the given [`syn::Type`] is a bare type parameter.
+    fn is_type_param(&self, ty: &syn::Type) -> bool {
+        matches!(
+            ty,
+            syn::Type::Path(p)
+                if p.qself.is_none()
+                    && p.path.get_ident().is_some_and(|i| {
+                        self.type_params.contains(i)
+                    }),
+        )
+    }
+}
+
+/// Extension of a [`syn::Type`].
+///
+/// Paths are matched by their last segment only, so `Option<T>`,
+/// `std::option::Option<T>` and `::core::option::Option<T>` are all treated
+/// the same way. Invisible groups and parentheses (like `(Option<T>)`) are
+/// looked through.
+///
+/// ```rust
+/// # use synthez::{TypeExt as _, syn};
+/// #
+/// let ty: syn::Type = syn::parse_quote! { ::core::option::Option<Vec<u8>> };
+/// let inner = ty.option_inner().unwrap();
+///
+/// assert_eq!(*inner, syn::parse_quote! { Vec<u8> });
+/// assert_eq!(
+///     inner.generic_args_of("Vec"),
+///     Some(vec![&syn::parse_quote!(u8)])
+/// );
+/// assert_eq!(inner.last_segment_ident().unwrap(), "Vec");
+///
+/// let ty: syn::Type = syn::parse_quote! { &'a &mut () };
+/// assert!(ty.strip_references().is_unit());
+/// ```
+#[sealed]
+pub trait Type {
+    /// Returns the [`syn::Ident`] of the last segment of this [`syn::Type`]
+    /// path, if it represents a path (like `Vec` for `std::vec::Vec<T>`).
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn last_segment_ident(&self) -> Option<&syn::Ident>;
+
+    /// Returns the type arguments of this [`syn::Type`], if its path ends with
+    /// a segment of the given `name` (like `"Vec"`).
+    ///
+    /// Lifetime and const arguments are omitted.
+    #[must_use]
+    fn generic_args_of(&self, name: &str) -> Option<Vec<&syn::Type>>;
+
+    /// Returns the inner [`syn::Type`] of this [`syn::Type`], if it represents
+    /// an `Option<T>`.
+    #[must_use]
+    fn option_inner(&self) -> Option<&syn::Type>;
+
+    /// Indicates whether this [`syn::Type`] represents a unit type `()`.
+    #[must_use]
+    fn is_unit(&self) -> bool;
+
+    /// Strips all the references (both shared and mutable ones) from this
+    /// [`syn::Type`] (like `T` for `&'a &mut T`).
+    #[must_use]
+    fn strip_references(&self) -> &syn::Type;
+}
+
+#[sealed]
+impl Type for syn::Type {
+    fn last_segment_ident(&self) -> Option<&syn::Ident> {
+        let Self::Path(p) = ungroup(self) else {
+            return None;
+        };
+        p.path.segments.last().map(|s| &s.ident)
+    }
+
+    fn generic_args_of(&self, name: &str) -> Option<Vec<&Self>> {
+        let Self::Path(p) = ungroup(self) else {
+            return None;
+        };
+        let segment = p.path.segments.last()?;
+        if p.qself.is_some() || segment.ident != name {
+            return None;
+        }
+        Some(match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|arg| {
+                    if let syn::GenericArgument::Type(ty) = arg {
+                        Some(ty)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            syn::PathArguments::None | syn::PathArguments::Parenthesized(_) => {
+                vec![]
+            }
+        })
+    }
+
+    fn option_inner(&self) -> Option<&Self> {
+        match self.generic_args_of("Option")?.as_slice() {
+            [inner] => Some(inner),
+            _ => None,
+        }
+    }
+
+    fn is_unit(&self) -> bool {
+        matches!(ungroup(self), Self::Tuple(t) if t.elems.is_empty())
+    }
+
+    fn strip_references(&self) -> &Self {
+        let ty = ungroup(self);
+        if let Self::Reference(r) = ty { r.elem.strip_references() } else { ty }
+    }
+}
+
+/// Looks through invisible groups and parentheses of the given [`syn::Type`].
+fn ungroup(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Group(g) = ty {
+        ungroup(&g.elem)
+    } else if let syn::Type::Paren(p) = ty {
+        ungroup(&p.elem)
+    } else {
+        ty
+    }
 }
 
 /// Extension of a [`syn::Ident`](struct@syn::Ident).
@@ -160,6 +1087,55 @@ pub trait Ident {
     /// [`syn::Ident`]: struct@syn::Ident
     #[must_use]
     fn new_on_call_site(ident: &str) -> syn::Ident;
+
+    /// Creates a new [`syn::Ident`] out of the given string value with a
+    /// [`Span::mixed_site`].
+    ///
+    /// Use [`ident::Factory`] for producing [`syn::Ident`]s guaranteed not to
+    /// collide with the existing ones.
+    ///
+    /// [`ident::Factory`]: crate::ident::Factory
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn new_on_mixed_site(ident: &str) -> syn::Ident;
+
+    /// Creates a new [`syn::Ident`] out of the given string value with the
+    /// provided [`Span`], escaping it if it's not usable as a plain identifier.
+    ///
+    /// Escaping means:
+    /// - a raw identifier (like `r#type`) for a Rust keyword;
+    /// - a trailing `_` (like `self_`) for a Rust keyword not allowed to be
+    ///   a raw identifier;
+    /// - a leading `_` (like `_1st`) for a value starting with a digit.
+    ///
+    /// # Panics
+    ///
+    /// If the given string value is not a valid identifier even after
+    /// escaping (like `kebab-case`).
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn new_escaped(ident: &str, span: Span) -> syn::Ident;
+
+    /// Returns the string value of this [`syn::Ident`] converted into the
+    /// given [`Case`], stripping its `r#` prefix, if any.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn to_case_string(&self, case: Case) -> String;
+
+    /// Converts this [`syn::Ident`] into the given [`Case`], preserving its
+    /// [`Span`] and escaping the result with [`Ident::new_escaped()`], if
+    /// required.
+    ///
+    /// # Panics
+    ///
+    /// If the given [`Case`] doesn't produce valid identifiers (like
+    /// [`Case::Kebab`]).
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    fn to_case(&self, case: Case) -> syn::Ident;
 }
 
 #[sealed]
@@ -168,4 +1144,30 @@ impl Ident for syn::Ident {
     fn new_on_call_site(ident: &str) -> Self {
         Self::new(ident, Span::call_site())
     }
+
+    #[inline]
+    fn new_on_mixed_site(ident: &str) -> Self {
+        Self::new(ident, Span::mixed_site())
+    }
+
+    fn new_escaped(ident: &str, span: Span) -> Self {
+        if case::is_non_raw_keyword(ident) {
+            Self::new(&format!("{ident}_"), span)
+        } else if case::is_keyword(ident) {
+            Self::new_raw(ident, span)
+        } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+            Self::new(&format!("_{ident}"), span)
+        } else {
+            Self::new(ident, span)
+        }
+    }
+
+    fn to_case_string(&self, case: Case) -> String {
+        let ident = self.to_string();
+        case.convert(ident.strip_prefix("r#").unwrap_or(&ident))
+    }
+
+    fn to_case(&self, case: Case) -> Self {
+        Self::new_escaped(&self.to_case_string(case), self.span())
+    }
 }
@@ -218,6 +218,12 @@ impl<T> Default for Required<T> {
 }
 
 impl<T> Required<T> {
+    /// Creates a new [`Required`] [`Container`] with the provided `value`.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
     /// Indicates whether the underlying value is present in this [`Required`]
     /// [`Container`].
     #[must_use]
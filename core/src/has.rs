@@ -28,6 +28,7 @@ impl_attrs_for! {
     syn::ConstParam,
     syn::DeriveInput,
     syn::Field,
+    syn::FnPtrVariadic,
     syn::LifetimeParam,
     syn::NamedArg,
     syn::TypeParam,
@@ -61,12 +62,14 @@ impl_attrs_full_for! {
     syn::ExprCall,
     syn::ExprCast,
     syn::ExprClosure,
+    syn::ExprConst,
     syn::ExprContinue,
     syn::ExprField,
     syn::ExprForLoop,
     syn::ExprGroup,
     syn::ExprIf,
     syn::ExprIndex,
+    syn::ExprInfer,
     syn::ExprLet,
     syn::ExprLit,
     syn::ExprLoop,
@@ -76,6 +79,7 @@ impl_attrs_full_for! {
     syn::ExprParen,
     syn::ExprPath,
     syn::ExprRange,
+    syn::ExprRawAddr,
     syn::ExprReference,
     syn::ExprRepeat,
     syn::ExprReturn,
@@ -114,8 +118,10 @@ impl_attrs_full_for! {
     syn::ItemUnion,
     syn::ItemUse,
     syn::Local,
+    syn::PatGuard,
     syn::PatIdent,
     syn::PatOr,
+    syn::PatParen,
     syn::PatReference,
     syn::PatRest,
     syn::PatSlice,
@@ -125,8 +131,169 @@ impl_attrs_full_for! {
     syn::PatType,
     syn::PatWild,
     syn::Receiver,
+    syn::StmtMacro,
     syn::TraitItemConst,
     syn::TraitItemFn,
     syn::TraitItemMacro,
     syn::TraitItemType,
+    syn::Variadic,
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::Expr {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Array(e) => e.attrs(),
+            Self::Assign(e) => e.attrs(),
+            Self::Async(e) => e.attrs(),
+            Self::Await(e) => e.attrs(),
+            Self::Binary(e) => e.attrs(),
+            Self::Block(e) => e.attrs(),
+            Self::Break(e) => e.attrs(),
+            Self::Call(e) => e.attrs(),
+            Self::Cast(e) => e.attrs(),
+            Self::Closure(e) => e.attrs(),
+            Self::Const(e) => e.attrs(),
+            Self::Continue(e) => e.attrs(),
+            Self::Field(e) => e.attrs(),
+            Self::ForLoop(e) => e.attrs(),
+            Self::Group(e) => e.attrs(),
+            Self::If(e) => e.attrs(),
+            Self::Index(e) => e.attrs(),
+            Self::Infer(e) => e.attrs(),
+            Self::Let(e) => e.attrs(),
+            Self::Lit(e) => e.attrs(),
+            Self::Loop(e) => e.attrs(),
+            Self::Macro(e) => e.attrs(),
+            Self::Match(e) => e.attrs(),
+            Self::MethodCall(e) => e.attrs(),
+            Self::Paren(e) => e.attrs(),
+            Self::Path(e) => e.attrs(),
+            Self::Range(e) => e.attrs(),
+            Self::RawAddr(e) => e.attrs(),
+            Self::Reference(e) => e.attrs(),
+            Self::Repeat(e) => e.attrs(),
+            Self::Return(e) => e.attrs(),
+            Self::Struct(e) => e.attrs(),
+            Self::Try(e) => e.attrs(),
+            Self::TryBlock(e) => e.attrs(),
+            Self::Tuple(e) => e.attrs(),
+            Self::Unary(e) => e.attrs(),
+            Self::Unsafe(e) => e.attrs(),
+            Self::While(e) => e.attrs(),
+            Self::Yield(e) => e.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::FnArg {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Receiver(r) => r.attrs(),
+            Self::Typed(p) => p.attrs(),
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::ForeignItem {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Fn(i) => i.attrs(),
+            Self::Static(i) => i.attrs(),
+            Self::Type(i) => i.attrs(),
+            Self::Macro(i) => i.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::ImplItem {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Const(i) => i.attrs(),
+            Self::Fn(i) => i.attrs(),
+            Self::Type(i) => i.attrs(),
+            Self::Macro(i) => i.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::Item {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Const(i) => i.attrs(),
+            Self::Enum(i) => i.attrs(),
+            Self::ExternCrate(i) => i.attrs(),
+            Self::Fn(i) => i.attrs(),
+            Self::ForeignMod(i) => i.attrs(),
+            Self::Impl(i) => i.attrs(),
+            Self::Macro(i) => i.attrs(),
+            Self::Mod(i) => i.attrs(),
+            Self::Static(i) => i.attrs(),
+            Self::Struct(i) => i.attrs(),
+            Self::Trait(i) => i.attrs(),
+            Self::TraitAlias(i) => i.attrs(),
+            Self::Type(i) => i.attrs(),
+            Self::Union(i) => i.attrs(),
+            Self::Use(i) => i.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::Pat {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Const(p) => p.attrs(),
+            Self::Guard(p) => p.attrs(),
+            Self::Ident(p) => p.attrs(),
+            Self::Lit(p) => p.attrs(),
+            Self::Macro(p) => p.attrs(),
+            Self::Or(p) => p.attrs(),
+            Self::Paren(p) => p.attrs(),
+            Self::Path(p) => p.attrs(),
+            Self::Range(p) => p.attrs(),
+            Self::Reference(p) => p.attrs(),
+            Self::Rest(p) => p.attrs(),
+            Self::Slice(p) => p.attrs(),
+            Self::Struct(p) => p.attrs(),
+            Self::Tuple(p) => p.attrs(),
+            Self::TupleStruct(p) => p.attrs(),
+            Self::Type(p) => p.attrs(),
+            Self::Wild(p) => p.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::Stmt {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Local(l) => l.attrs(),
+            Self::Item(i) => i.attrs(),
+            Self::Expr(e, _) => e.attrs(),
+            Self::Macro(m) => m.attrs(),
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl Attrs for syn::TraitItem {
+    fn attrs(&self) -> &[syn::Attribute] {
+        match self {
+            Self::Const(i) => i.attrs(),
+            Self::Fn(i) => i.attrs(),
+            Self::Type(i) => i.attrs(),
+            Self::Macro(i) => i.attrs(),
+            Self::Verbatim(_) | _ => &[],
+        }
+    }
 }
@@ -0,0 +1,134 @@
+//! Generation of hygienic unique [`syn::Ident`]s.
+//!
+//! [`syn::Ident`]: struct@syn::Ident
+
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Factory of [`syn::Ident`]s with a [`Span::mixed_site()`], guaranteed not to
+/// collide with any [`syn::Ident`] of the reserved code (like the
+/// [`syn::DeriveInput`] being expanded) or with each other.
+///
+/// ```rust
+/// # use synthez::{ident, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     struct Foo<__T> {
+///         __field: __T,
+///     }
+/// };
+/// let mut idents = ident::Factory::new(&input);
+///
+/// assert_eq!(idents.fresh("__T").to_string(), "__T_1");
+/// assert_eq!(idents.fresh("__T").to_string(), "__T_2");
+/// assert_eq!(idents.fresh("__other").to_string(), "__other");
+///
+/// let impl_ident = idents.fresh_from(&input.ident, |i| format!("__impl_{i}"));
+/// assert_eq!(impl_ident.to_string(), "__impl_Foo");
+/// ```
+///
+/// [`syn::Ident`]: struct@syn::Ident
+#[derive(Clone, Debug, Default)]
+pub struct Factory {
+    /// String values of all the already taken [`syn::Ident`]s.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    taken: HashSet<String>,
+}
+
+impl Factory {
+    /// Creates a new [`Factory`] reserving all the [`syn::Ident`]s found in the
+    /// given [`syn::DeriveInput`] (its fields, generics, variants, etc).
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    pub fn new(input: &syn::DeriveInput) -> Self {
+        let mut factory = Self::default();
+        factory.reserve(input);
+        factory
+    }
+
+    /// Reserves all the [`syn::Ident`]s found in the given `tokens`, so they
+    /// won't be produced by this [`Factory`].
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    pub fn reserve<T: ToTokens + ?Sized>(&mut self, tokens: &T) {
+        self.reserve_stream(tokens.to_token_stream());
+    }
+
+    /// Reserves all the [`syn::Ident`]s found in the given [`TokenStream`],
+    /// descending into its groups.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    fn reserve_stream(&mut self, tokens: TokenStream) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Ident(ident) => {
+                    let ident = ident.to_string();
+                    _ = self.taken.insert(
+                        ident.strip_prefix("r#").unwrap_or(&ident).to_owned(),
+                    );
+                }
+                TokenTree::Group(group) => self.reserve_stream(group.stream()),
+                TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+            }
+        }
+    }
+
+    /// Produces a new unique [`syn::Ident`] with a [`Span::mixed_site()`] out
+    /// of the given `base` value, suffixing it with a number, if it's taken
+    /// already.
+    ///
+    /// # Panics
+    ///
+    /// If the given `base` value is not a valid identifier.
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    pub fn fresh(&mut self, base: &str) -> syn::Ident {
+        self.fresh_spanned(base, Span::mixed_site())
+    }
+
+    /// Produces a new unique [`syn::Ident`] out of the given `base`
+    /// [`syn::Ident`] formatted with the provided `format` function (in the
+    /// manner of [`format_ident!`]), suffixing it with a number, if it's taken
+    /// already.
+    ///
+    /// The produced [`syn::Ident`] is located at the source [`Span`] of the
+    /// `base` [`syn::Ident`], while having a [`Span::mixed_site()`] hygiene.
+    ///
+    /// # Panics
+    ///
+    /// If the formatted value is not a valid identifier.
+    ///
+    /// [`format_ident!`]: quote::format_ident
+    /// [`syn::Ident`]: struct@syn::Ident
+    #[must_use]
+    pub fn fresh_from(
+        &mut self,
+        base: &syn::Ident,
+        format: impl FnOnce(&str) -> String,
+    ) -> syn::Ident {
+        let value = base.to_string();
+        let value = format(value.strip_prefix("r#").unwrap_or(&value));
+        self.fresh_spanned(&value, Span::mixed_site().located_at(base.span()))
+    }
+
+    /// Produces a new unique [`syn::Ident`] out of the given `base` value with
+    /// the provided [`Span`].
+    ///
+    /// [`syn::Ident`]: struct@syn::Ident
+    fn fresh_spanned(&mut self, base: &str, span: Span) -> syn::Ident {
+        let mut value = base.to_owned();
+        let mut n = 0_usize;
+        while self.taken.contains(&value) {
+            n += 1;
+            value = format!("{base}_{n}");
+        }
+        let ident = syn::Ident::new(&value, span);
+        _ = self.taken.insert(value);
+        ident
+    }
+}
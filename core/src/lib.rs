@@ -148,10 +148,12 @@
     variant_size_differences
 )]
 
+pub mod case;
 pub mod codegen;
 pub mod ext;
 pub mod field;
 pub mod has;
+pub mod ident;
 pub mod parse;
 pub mod spanned;
 
@@ -167,8 +169,14 @@ pub use syn;
 
 #[doc(inline)]
 pub use self::{
-    ext::{Data as DataExt, Ident as IdentExt},
+    ext::{
+        Data as DataExt, Fields as FieldsExt, Generics as GenericsExt,
+        Ident as IdentExt, Type as TypeExt,
+    },
     field::Required,
-    parse::{Attrs as ParseAttrs, BufferExt as ParseBufferExt},
+    parse::{
+        Attrs as ParseAttrs, BufferExt as ParseBufferExt,
+        IdentEnum as ParseIdentEnum,
+    },
     spanned::Spanning,
 };
@@ -1,6 +1,13 @@
 //! Batteries for parsing a single attribute.
 
-use crate::Spanning;
+use syn::{parse::ParseStream, punctuated::Punctuated, token};
+
+use super::{
+    attrs::{dedup, field::TryMerge as _, kind},
+    err,
+    ext::ParseBuffer as _,
+};
+use crate::{Spanning, spanned};
 
 /// Lookups for the standard Rust `#[doc]` attributes in the given
 /// [`syn::Attribute`]s and parses their text as [`String`] with a little
@@ -67,3 +74,574 @@ pub fn doc_string(
 pub fn doc(attrs: &[syn::Attribute]) -> syn::Result<Option<syn::LitStr>> {
     doc_string(attrs).map(|opt| opt.map(Into::into))
 }
+
+/// Structured text of the standard Rust `#[doc]` attributes.
+///
+/// Every line is kept along with the [`Span`] of the `#[doc]` attribute it
+/// comes from, while [`Docs::summary()`], [`Docs::body()`],
+/// [`Docs::code_blocks()`] and [`Docs::headings()`] describe the Markdown
+/// structure of the text.
+///
+/// ```rust
+/// # use synthez::{parse::attr::Docs, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     /// Summary of
+///     /// `Foo`.
+///     ///
+///     /// # Example
+///     ///
+///     /// ```rust
+///     /// # use foo::Foo;
+///     /// let foo = Foo;
+///     /// ```
+///     #[doc = include_str!("../README.md")]
+///     struct Foo;
+/// };
+/// let docs = Docs::from_attrs(&input.attrs).unwrap();
+///
+/// assert_eq!(docs.summary().as_deref().unwrap(), "Summary of\n`Foo`.");
+/// assert_eq!(docs.headings()[0].title.as_str(), "Example");
+///
+/// let code = &docs.code_blocks()[0];
+/// assert_eq!(code.lang.as_deref(), Some("rust"));
+/// assert_eq!(code.code.as_str(), "# use foo::Foo;\nlet foo = Foo;");
+///
+/// assert_eq!(docs.unresolved().len(), 1);
+/// ```
+///
+/// [`Span`]: proc_macro2::Span
+#[derive(Clone, Debug, Default)]
+pub struct Docs {
+    /// Lines of the text, along with the [`Span`]s of the `#[doc]` attributes
+    /// they come from.
+    ///
+    /// [`Span`]: proc_macro2::Span
+    lines: Vec<Spanning<String>>,
+
+    /// Values of the `#[doc]` attributes, which cannot be resolved into a text
+    /// at parsing time (like `#[doc = include_str!("README.md")]`).
+    unresolved: Vec<syn::Expr>,
+}
+
+impl Docs {
+    /// Lookups for the standard Rust `#[doc]` attributes in the given
+    /// [`syn::Attribute`]s and parses their text into [`Docs`].
+    ///
+    /// Values of `#[doc]` attributes, other than literals, are not errors, but
+    /// are collected into [`Docs::unresolved()`] instead.
+    ///
+    /// # Errors
+    ///
+    /// If `#[doc]` attribute contains a non-string literal.
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut out = Self::default();
+        for a in super::attrs::filter_by_name("doc", attrs) {
+            let syn::Meta::NameValue(item) = &a.meta else { continue };
+            if let syn::Expr::Lit(expr) = &item.value {
+                let syn::Lit::Str(lit) = &expr.lit else {
+                    return Err(syn::Error::new_spanned(
+                        &expr.lit,
+                        "`#[doc]` attribute can contain string literals only",
+                    ));
+                };
+                let span = lit.span();
+                out.lines.extend(lit.value().split('\n').map(|l| {
+                    let l = l.strip_prefix(' ').unwrap_or(l).trim_end();
+                    Spanning::new(l.to_owned(), span)
+                }));
+            } else {
+                out.unresolved.push(item.value.clone());
+            }
+        }
+        Ok(out)
+    }
+
+    /// Indicates whether these [`Docs`] contain no text.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|l| l.is_empty())
+    }
+
+    /// Returns all the lines of the text, along with the [`Span`]s of the
+    /// `#[doc]` attributes they come from.
+    ///
+    /// [`Span`]: proc_macro2::Span
+    #[must_use]
+    pub fn lines(&self) -> &[Spanning<String>] {
+        &self.lines
+    }
+
+    /// Returns values of the `#[doc]` attributes, which cannot be resolved
+    /// into a text at parsing time (like `#[doc = include_str!("README.md")]`),
+    /// so are not present in the [`Docs::lines()`].
+    #[must_use]
+    pub fn unresolved(&self) -> &[syn::Expr] {
+        &self.unresolved
+    }
+
+    /// Returns the whole text, with leading and trailing empty lines trimmed.
+    #[must_use]
+    pub fn text(&self) -> Option<Spanning<String>> {
+        join_lines(trim_empty(&self.lines))
+    }
+
+    /// Returns the summary of the text (its first paragraph), if the text
+    /// starts with a paragraph.
+    #[must_use]
+    pub fn summary(&self) -> Option<Spanning<String>> {
+        join_lines(self.summary_lines())
+    }
+
+    /// Returns the text following the [`Docs::summary()`], with leading and
+    /// trailing empty lines trimmed.
+    #[must_use]
+    pub fn body(&self) -> Option<Spanning<String>> {
+        let skip = self.leading_empty() + self.summary_lines().len();
+        join_lines(trim_empty(self.lines.get(skip..).unwrap_or_default()))
+    }
+
+    /// Returns all the fenced [`CodeBlock`]s of the text, in their order.
+    #[must_use]
+    pub fn code_blocks(&self) -> Vec<CodeBlock> {
+        self.blocks()
+            .into_iter()
+            .filter_map(|b| match b {
+                Block::Code(code) => Some(code),
+                Block::Heading(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns all the [`Heading`]s of the text (outside of
+    /// [`CodeBlock`]s), in their order.
+    #[must_use]
+    pub fn headings(&self) -> Vec<Heading> {
+        self.blocks()
+            .into_iter()
+            .filter_map(|b| match b {
+                Block::Heading(heading) => Some(heading),
+                Block::Code(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the number of leading empty lines of the text.
+    fn leading_empty(&self) -> usize {
+        self.lines.iter().take_while(|l| l.is_empty()).count()
+    }
+
+    /// Returns the lines of the first paragraph of the text, if the text
+    /// starts with a paragraph.
+    fn summary_lines(&self) -> &[Spanning<String>] {
+        let lines = self.lines.get(self.leading_empty()..).unwrap_or_default();
+        let len = lines
+            .iter()
+            .take_while(|l| {
+                !l.is_empty() && !is_fence(l) && heading_level(l).is_none()
+            })
+            .count();
+        lines.get(..len).unwrap_or_default()
+    }
+
+    /// Splits the text into [`Block`]s.
+    fn blocks(&self) -> Vec<Block> {
+        let mut out = vec![];
+        let mut lines = self.lines.iter();
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim_start();
+            if let Some(fence) = fence_of(trimmed) {
+                let lang =
+                    trimmed.get(fence.len()..).unwrap_or_default().trim();
+                let mut span = line.span();
+                let mut code = vec![];
+                for l in lines.by_ref() {
+                    span = spanned::join(span, l.span());
+                    if l.trim().starts_with(fence) {
+                        break;
+                    }
+                    code.push(l.as_str());
+                }
+                out.push(Block::Code(CodeBlock {
+                    lang: (!lang.is_empty()).then(|| lang.to_owned()),
+                    code: Spanning::new(code.join("\n"), span),
+                }));
+            } else if let Some(level) = heading_level(line) {
+                let title = trimmed.get(level..).unwrap_or_default().trim();
+                out.push(Block::Heading(Heading {
+                    level,
+                    title: Spanning::new(title.to_owned(), line.span()),
+                }));
+            }
+        }
+        out
+    }
+}
+
+/// Fenced code block in [`Docs`].
+#[derive(Clone, Debug)]
+pub struct CodeBlock {
+    /// Info string of the opening fence (like `rust,ignore`), if any.
+    pub lang: Option<String>,
+
+    /// Verbatim code inside the fences, along with the [`Span`] of the whole
+    /// block.
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub code: Spanning<String>,
+}
+
+/// Heading (like `# Example`) in [`Docs`].
+#[derive(Clone, Debug)]
+pub struct Heading {
+    /// Level of this [`Heading`] (number of `#`s).
+    pub level: usize,
+
+    /// Title of this [`Heading`], along with its [`Span`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    pub title: Spanning<String>,
+}
+
+/// Structural part of [`Docs`] text.
+enum Block {
+    /// Fenced [`CodeBlock`].
+    Code(CodeBlock),
+
+    /// [`Heading`] line.
+    Heading(Heading),
+}
+
+/// Returns the fence (` ``` ` or `~~~`) opening a code block on the given
+/// `line`, if any.
+fn fence_of(line: &str) -> Option<&'static str> {
+    ["```", "~~~"].into_iter().find(|f| line.starts_with(f))
+}
+
+/// Checks whether the given `line` opens or closes a code block.
+fn is_fence(line: &str) -> bool {
+    fence_of(line.trim_start()).is_some()
+}
+
+/// Returns the level of the heading on the given `line`, if it's a heading.
+fn heading_level(line: &str) -> Option<usize> {
+    let line = line.trim_start();
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = line.get(level..)?;
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')))
+        .then_some(level)
+}
+
+/// Trims leading and trailing empty lines of the given `lines`.
+fn trim_empty(lines: &[Spanning<String>]) -> &[Spanning<String>] {
+    let start = lines.iter().take_while(|l| l.is_empty()).count();
+    let end =
+        lines.len() - lines.iter().rev().take_while(|l| l.is_empty()).count();
+    lines.get(start..end).unwrap_or_default()
+}
+
+/// Joins the given `lines` into a single text with their [`Span`]s joined,
+/// if there are any.
+///
+/// [`Span`]: proc_macro2::Span
+fn join_lines(lines: &[Spanning<String>]) -> Option<Spanning<String>> {
+    let (first, last) = (lines.first()?, lines.last()?);
+    let text = lines.iter().map(|l| l.as_str()).collect::<Vec<_>>().join("\n");
+    Some(Spanning::new(text, spanned::join(first.span(), last.span())))
+}
+
+/// Parsed standard Rust `#[deprecated]` attribute.
+#[derive(Clone, Debug, Default)]
+pub struct Deprecated {
+    /// Version since which the item is deprecated, if specified.
+    pub since: Option<syn::LitStr>,
+
+    /// Reason of the deprecation, if specified.
+    pub note: Option<syn::LitStr>,
+}
+
+/// Lookups for the standard Rust `#[deprecated]` attribute in the given
+/// [`syn::Attribute`]s and parses it into a [`Deprecated`], along with the
+/// [`Span`] of the attribute.
+///
+/// Supports all the `#[deprecated]`, `#[deprecated = "note"]` and
+/// `#[deprecated(since = "version", note = "note")]` forms.
+///
+/// ```rust
+/// # use synthez::{parse::attr, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     #[deprecated(since = "1.2.0", note = "use `Bar` instead")]
+///     struct Foo;
+/// };
+/// let deprecated = attr::deprecated(&input.attrs).unwrap().unwrap();
+///
+/// assert_eq!(deprecated.since.as_ref().unwrap().value(), "1.2.0");
+/// assert_eq!(deprecated.note.as_ref().unwrap().value(), "use `Bar` instead");
+/// ```
+///
+/// # Errors
+///
+/// - If `#[deprecated]` attribute is malformed.
+/// - If `#[deprecated]` attribute is duplicated.
+///
+/// [`Span`]: proc_macro2::Span
+pub fn deprecated(
+    attrs: &[syn::Attribute],
+) -> syn::Result<Option<Spanning<Deprecated>>> {
+    let mut out = None;
+    for a in super::attrs::filter_by_name("deprecated", attrs) {
+        let parsed = match &a.meta {
+            syn::Meta::Path(_) => Deprecated::default(),
+            syn::Meta::NameValue(item) => Deprecated {
+                since: None,
+                note: Some(syn::parse2(quote::ToTokens::to_token_stream(
+                    &item.value,
+                ))?),
+            },
+            syn::Meta::List(_) => a.parse_args_with(parse_deprecated_args)?,
+        };
+        out.try_merge::<kind::Value, dedup::Unique>(Spanning::new(parsed, a))?;
+    }
+    Ok(out)
+}
+
+/// Parses arguments of a `#[deprecated(since = "version", note = "note")]`
+/// attribute.
+fn parse_deprecated_args(input: ParseStream<'_>) -> syn::Result<Deprecated> {
+    let mut out = Deprecated::default();
+    while !input.is_empty() {
+        let ident = input.parse_any_ident()?;
+        let field = match ident.to_string().as_str() {
+            "since" => &mut out.since,
+            "note" => &mut out.note,
+            name => return Err(err::unknown_attr_arg(&ident, name)),
+        };
+        _ = input.parse::<token::Eq>()?;
+        field.try_merge::<kind::Value, dedup::Unique>(input.parse()?)?;
+        if input.try_parse::<token::Comma>()?.is_none() && !input.is_empty() {
+            return Err(err::expected_followed_by_comma(&ident));
+        }
+    }
+    Ok(out)
+}
+
+/// Parsed standard Rust `#[must_use]` attribute.
+#[derive(Clone, Debug, Default)]
+pub struct MustUse {
+    /// Reason of why the value must be used, if specified.
+    pub note: Option<syn::LitStr>,
+}
+
+/// Lookups for the standard Rust `#[must_use]` attribute in the given
+/// [`syn::Attribute`]s and parses it into a [`MustUse`], along with the
+/// [`Span`] of the attribute.
+///
+/// Supports both the `#[must_use]` and `#[must_use = "note"]` forms.
+///
+/// ```rust
+/// # use synthez::{parse::attr, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     #[must_use = "does nothing unless polled"]
+///     struct Foo;
+/// };
+/// let must_use = attr::must_use(&input.attrs).unwrap().unwrap();
+///
+/// assert_eq!(
+///     must_use.note.as_ref().unwrap().value(),
+///     "does nothing unless polled",
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - If `#[must_use]` attribute is malformed.
+/// - If `#[must_use]` attribute is duplicated.
+///
+/// [`Span`]: proc_macro2::Span
+pub fn must_use(
+    attrs: &[syn::Attribute],
+) -> syn::Result<Option<Spanning<MustUse>>> {
+    let mut out = None;
+    for a in super::attrs::filter_by_name("must_use", attrs) {
+        let parsed = match &a.meta {
+            syn::Meta::Path(_) => MustUse::default(),
+            syn::Meta::NameValue(item) => MustUse {
+                note: Some(syn::parse2(quote::ToTokens::to_token_stream(
+                    &item.value,
+                ))?),
+            },
+            syn::Meta::List(list) => {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "`#[must_use]` attribute should be in \
+                     `#[must_use = \"note\"]` format",
+                ));
+            }
+        };
+        out.try_merge::<kind::Value, dedup::Unique>(Spanning::new(parsed, a))?;
+    }
+    Ok(out)
+}
+
+/// Parsed standard Rust `#[repr]` attributes.
+///
+/// All the `#[repr]` attributes of an item are merged into a single [`Repr`].
+///
+/// ```rust
+/// # use synthez::{parse::attr, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     #[repr(C, u8)]
+///     #[repr(align(8))]
+///     enum Foo { Bar }
+/// };
+/// let repr = attr::repr(&input.attrs).unwrap();
+///
+/// assert!(repr.c.is_some());
+/// assert!(repr.rust.is_none());
+/// assert_eq!(repr.int.unwrap().to_string(), "u8");
+/// assert_eq!(repr.align.unwrap().base10_parse::<u32>().unwrap(), 8);
+/// assert!(repr.transparent.is_none());
+/// assert!(repr.packed.is_none());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Repr {
+    /// Primitive integer representation (like `u8` or `isize`), if specified.
+    pub int: Option<syn::Ident>,
+
+    /// `Rust` representation, if specified.
+    pub rust: Option<syn::Ident>,
+
+    /// `C` representation, if specified.
+    pub c: Option<syn::Ident>,
+
+    /// `transparent` representation, if specified.
+    pub transparent: Option<syn::Ident>,
+
+    /// `align(N)` modifier, if specified.
+    pub align: Option<syn::LitInt>,
+
+    /// `packed` or `packed(N)` modifier, if specified.
+    pub packed: Option<Spanning<Option<syn::LitInt>>>,
+}
+
+impl Repr {
+    /// Primitive integer types allowed in `#[repr]` attributes.
+    pub const INTS: [&'static str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize",
+    ];
+
+    /// Indicates whether this [`Repr`] specifies nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.int.is_none()
+            && self.rust.is_none()
+            && self.c.is_none()
+            && self.transparent.is_none()
+            && self.align.is_none()
+            && self.packed.is_none()
+    }
+}
+
+/// Lookups for the standard Rust `#[repr]` attributes in the given
+/// [`syn::Attribute`]s and parses them into a single [`Repr`].
+///
+/// # Errors
+///
+/// - If `#[repr]` attribute is malformed or contains unknown representation.
+/// - If the same representation is specified several times.
+pub fn repr(attrs: &[syn::Attribute]) -> syn::Result<Repr> {
+    let mut out = Repr::default();
+    for a in super::attrs::filter_by_name("repr", attrs) {
+        a.parse_args_with(|input: ParseStream<'_>| {
+            while !input.is_empty() {
+                let ident = input.parse_any_ident()?;
+                match ident.to_string().as_str() {
+                    "Rust" => {
+                        out.rust.try_merge::<kind::Ident, dedup::Unique>(
+                            ident.clone(),
+                        )?;
+                    }
+                    "C" => out.c.try_merge::<kind::Ident, dedup::Unique>(
+                        ident.clone(),
+                    )?,
+                    "transparent" => out
+                        .transparent
+                        .try_merge::<kind::Ident, dedup::Unique>(
+                            ident.clone(),
+                        )?,
+                    "align" => {
+                        let content;
+                        _ = syn::parenthesized!(content in input);
+                        out.align.try_merge::<kind::Value, dedup::Unique>(
+                            content.parse()?,
+                        )?;
+                    }
+                    "packed" => {
+                        let value = if input.peek(token::Paren) {
+                            let content;
+                            _ = syn::parenthesized!(content in input);
+                            Some(content.parse()?)
+                        } else {
+                            None
+                        };
+                        out.packed.try_merge::<kind::Value, dedup::Unique>(
+                            Spanning::new(value, &ident),
+                        )?;
+                    }
+                    name if Repr::INTS.contains(&name) => {
+                        out.int.try_merge::<kind::Ident, dedup::Unique>(
+                            ident.clone(),
+                        )?;
+                    }
+                    name => return Err(err::unknown_attr_arg(&ident, name)),
+                }
+                if input.try_parse::<token::Comma>()?.is_none()
+                    && !input.is_empty()
+                {
+                    return Err(err::expected_followed_by_comma(&ident));
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(out)
+}
+
+/// Lookups for the standard Rust `#[derive]` attributes in the given
+/// [`syn::Attribute`]s and parses paths of all the derived traits.
+///
+/// ```rust
+/// # use synthez::{parse::attr, quote::ToTokens as _, syn};
+/// #
+/// let input: syn::DeriveInput = syn::parse_quote! {
+///     #[derive(Clone, serde::Serialize)]
+///     #[derive(Debug)]
+///     struct Foo;
+/// };
+/// let derives = attr::derives(&input.attrs).unwrap();
+///
+/// assert_eq!(
+///     derives
+///         .iter()
+///         .map(|p| p.to_token_stream().to_string())
+///         .collect::<Vec<_>>(),
+///     ["Clone", "serde :: Serialize", "Debug"],
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If `#[derive]` attribute is malformed.
+pub fn derives(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::Path>> {
+    let mut out = vec![];
+    for a in super::attrs::filter_by_name("derive", attrs) {
+        out.extend(a.parse_args_with(
+            Punctuated::<syn::Path, token::Comma>::parse_terminated,
+        )?);
+    }
+    Ok(out)
+}
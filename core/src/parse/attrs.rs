@@ -1,10 +1,17 @@
 //! Machinery for parsing [`syn::Attribute`]s into a custom defined struct.
 
 use proc_macro2::Span;
-use syn::parse::Parse;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, Parser as _},
+    punctuated::Punctuated,
+    token,
+};
 
 #[doc(inline)]
-pub use self::{dedup::Dedup, kind::Kind, validate::Validation};
+pub use self::{
+    dedup::Dedup, kind::Kind, schema::Schema, validate::Validation,
+};
 use super::err;
 use crate::{has, spanned::IntoSpan};
 
@@ -43,6 +50,16 @@ pub trait Attrs: Default + Parse {
         Ok(())
     }
 
+    /// Returns the [`Schema`] describing arguments of [`syn::Attribute`]s
+    /// parsed into this structure.
+    ///
+    /// Default implementation describes no arguments.
+    #[inline]
+    #[must_use]
+    fn schema() -> Schema {
+        Schema::default()
+    }
+
     /// Parses this structure from the [`syn::Attribute`]s with the given `name`
     /// and contained in the given `item`.
     ///
@@ -70,6 +87,112 @@ pub trait Attrs: Default + Parse {
         parsed.validate(name, item.into_span())?;
         Ok(parsed)
     }
+
+    /// Parses this structure from the given [`syn::Meta`] (like the one of a
+    /// [`syn::Attribute`]), considering its path as the attribute's name.
+    ///
+    /// A [`syn::Meta::Path`] (like `#[my_attr]`) is considered as containing no
+    /// arguments. Note, that [`Attrs::fallback()`] is not applied, as there
+    /// are no [`syn::Attribute`]s to fall back to.
+    ///
+    /// # Errors
+    ///
+    /// - If the given [`syn::Meta`] is a [`syn::Meta::NameValue`].
+    /// - If [`Parse`]ing of this [`Attrs`] fails.
+    /// - If [`Attrs::validate()`] fails.
+    fn from_meta(meta: &syn::Meta) -> syn::Result<Self> {
+        let parsed = match meta {
+            syn::Meta::Path(_) => Self::default(),
+            syn::Meta::List(list) => list.parse_args()?,
+            syn::Meta::NameValue(item) => {
+                return Err(syn::Error::new_spanned(
+                    item.eq_token,
+                    "expected attribute arguments in parentheses",
+                ));
+            }
+        };
+        parsed.validate(&path_to_string(meta.path()), meta.into_span())?;
+        Ok(parsed)
+    }
+
+    /// Parses this structure from the given [`ParseNestedMeta`] (while using
+    /// [`syn::Attribute::parse_nested_meta()`]), considering its path as the
+    /// attribute's name.
+    ///
+    /// A [`ParseNestedMeta`] not followed by parentheses (like `nested` in
+    /// `#[my_attr(nested)]`) is considered as containing no arguments.
+    ///
+    /// # Errors
+    ///
+    /// - If [`Parse`]ing of this [`Attrs`] fails.
+    /// - If [`Attrs::validate()`] fails.
+    ///
+    /// [`ParseNestedMeta`]: syn::meta::ParseNestedMeta
+    fn from_nested_meta(
+        meta: &syn::meta::ParseNestedMeta<'_>,
+    ) -> syn::Result<Self> {
+        let parsed = if meta.input.peek(token::Paren) {
+            let content;
+            _ = syn::parenthesized!(content in meta.input);
+            content.parse()?
+        } else {
+            Self::default()
+        };
+        parsed.validate(&path_to_string(&meta.path), meta.path.into_span())?;
+        Ok(parsed)
+    }
+
+    /// Renders this structure back into a [`syn::Meta`] with the given `name`,
+    /// so it can be parsed again with [`Attrs::from_meta()`].
+    ///
+    /// # Panics
+    ///
+    /// If the given `name` is not a valid identifier.
+    #[must_use]
+    fn to_meta(&self, name: &str) -> syn::Meta
+    where
+        Self: ToTokens,
+    {
+        syn::Meta::List(syn::MetaList {
+            path: syn::Ident::new(name, Span::call_site()).into(),
+            delimiter: syn::MacroDelimiter::Paren(token::Paren::default()),
+            tokens: self.to_token_stream(),
+        })
+    }
+
+    /// Renders arguments of this structure as separate [`syn::Meta`]s (like
+    /// `skip` and `rename = "foo"`).
+    ///
+    /// # Errors
+    ///
+    /// If any rendered argument is not a valid [`syn::Meta`].
+    fn to_nested_metas(&self) -> syn::Result<Vec<syn::Meta>>
+    where
+        Self: ToTokens,
+    {
+        Punctuated::<syn::Meta, token::Comma>::parse_terminated
+            .parse2(self.to_token_stream())
+            .map(|metas| metas.into_iter().collect())
+    }
+
+    /// Renders this structure back into a [`syn::Attribute`] with the given
+    /// `name`, so it can be parsed again with [`Attrs::parse_attrs()`].
+    ///
+    /// # Panics
+    ///
+    /// If the given `name` is not a valid identifier.
+    #[must_use]
+    fn to_attribute(&self, name: &str) -> syn::Attribute
+    where
+        Self: ToTokens,
+    {
+        syn::Attribute {
+            pound_token: token::Pound::default(),
+            style: syn::AttrStyle::Outer,
+            bracket_token: token::Bracket::default(),
+            meta: self.to_meta(name),
+        }
+    }
 }
 
 impl<V: Attrs + Default + Parse> Attrs for Box<V> {
@@ -85,6 +208,10 @@ impl<V: Attrs + Default + Parse> Attrs for Box<V> {
         (**self).fallback(attrs)
     }
 
+    fn schema() -> Schema {
+        V::schema()
+    }
+
     fn parse_attrs<T>(name: &str, item: &T) -> syn::Result<Self>
     where
         T: has::Attrs,
@@ -94,6 +221,24 @@ impl<V: Attrs + Default + Parse> Attrs for Box<V> {
     }
 }
 
+/// Inheritance of values from parent [`Attrs`] (like a container-level
+/// [`syn::Attribute`] inherited by field-level ones).
+///
+/// All the methods have default implementations inheriting nothing, so an
+/// empty `impl` is enough to opt out of inheritance.
+pub trait Inherit<P: ?Sized> {
+    /// Inherits the required values from the given `parent` [`Attrs`], if
+    /// they're not specified in these ones.
+    ///
+    /// # Errors
+    ///
+    /// If inheriting fails.
+    #[inline]
+    fn inherit(&mut self, _parent: &P) -> syn::Result<()> {
+        Ok(())
+    }
+}
+
 /// Filters the provided `attrs` to contain [`syn::Attribute`]s only with the
 /// provided `name`.
 pub fn filter_by_name<'a>(
@@ -103,6 +248,13 @@ pub fn filter_by_name<'a>(
     attrs.iter().filter(move |attr| path_eq_single(attr.meta.path(), name))
 }
 
+/// Renders the given `path` as a string (like `foo::bar`).
+fn path_to_string(path: &syn::Path) -> String {
+    let segments = path.segments.iter().map(|s| s.ident.to_string());
+    let joined = segments.collect::<Vec<_>>().join("::");
+    if path.leading_colon.is_some() { format!("::{joined}") } else { joined }
+}
+
 /// Compares the given `path` with the one-segment string `value` to be equal.
 #[must_use]
 fn path_eq_single(path: &syn::Path, value: &str) -> bool {
@@ -114,10 +266,15 @@ pub mod field {
     //!
     //! [`Attrs`]: super::Attrs
 
+    use std::ops::Deref;
+
+    use proc_macro2::{Span, TokenStream};
+    use quote::{ToTokens, quote};
     use sealed::sealed;
+    use syn::{punctuated::Punctuated, token};
 
-    use super::{Dedup, Kind};
-    use crate::field;
+    use super::{Dedup, Kind, kind};
+    use crate::{field, spanned::Spanning};
 
     /// Applying a value to a [`field::Container`] according to a parsing
     /// [`Kind`] and [`Dedup`]lication strategy.
@@ -721,6 +878,163 @@ pub mod field {
         }
     }
 
+    /// Rendering values of a [`field::Container`] back into
+    /// [`syn::Attribute`]'s arguments according to a parsing [`Kind`].
+    pub trait ToArgs<V, K: Kind + ?Sized>: field::Container<V> {
+        /// Renders values of this [`field::Container`] as [`syn::Attribute`]'s
+        /// arguments with the given `name`, pushing them into the provided
+        /// `args`.
+        fn to_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        );
+    }
+
+    /// Rendering a single value of a [`field::Container`] in [`ToArgs`].
+    ///
+    /// Implemented for all the [`ToTokens`] types, and for [`Spanning`] ones
+    /// too, re-applying their held [`Span`] to the rendered tokens (see
+    /// [`Spanning::to_respanned_tokens()`]).
+    #[sealed]
+    pub trait ToArgTokens {
+        /// Renders this value into a [`TokenStream`].
+        #[must_use]
+        fn to_arg_tokens(&self) -> TokenStream;
+    }
+
+    #[sealed]
+    impl<T: ToTokens + ?Sized> ToArgTokens for T {
+        fn to_arg_tokens(&self) -> TokenStream {
+            self.to_token_stream()
+        }
+    }
+
+    #[sealed]
+    impl<T: ToTokens + ?Sized> ToArgTokens for Spanning<T> {
+        fn to_arg_tokens(&self) -> TokenStream {
+            self.to_respanned_tokens()
+        }
+    }
+
+    impl<C, V> ToArgs<V, kind::Ident> for C
+    where
+        C: field::Container<V> + ?Sized,
+        for<'a> &'a C: IntoIterator<Item = &'a V>,
+        V: ToArgTokens,
+    {
+        fn to_args(
+            &self,
+            _: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        ) {
+            for v in self {
+                args.push(v.to_arg_tokens());
+            }
+        }
+    }
+
+    impl<C, V> ToArgs<V, kind::Nested> for C
+    where
+        C: field::Container<V> + ?Sized,
+        for<'a> &'a C: IntoIterator<Item = &'a V>,
+        V: Deref<Target: ToTokens>,
+    {
+        fn to_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        ) {
+            let name = syn::Ident::new(name, Span::call_site());
+            for v in self {
+                let v = &**v;
+                args.push(quote! { #name(#v) });
+            }
+        }
+    }
+
+    impl<C, V> ToArgs<V, kind::Value> for C
+    where
+        C: field::Container<V> + ?Sized,
+        for<'a> &'a C: IntoIterator<Item = &'a V>,
+        V: ToArgTokens,
+    {
+        fn to_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        ) {
+            let vals = self
+                .into_iter()
+                .map(ToArgTokens::to_arg_tokens)
+                .collect::<Punctuated<_, token::Comma>>();
+            let name = syn::Ident::new(name, Span::call_site());
+            match vals.len() {
+                0 => {}
+                1 => args.push(quote! { #name = #vals }),
+                _ => args.push(quote! { #name(#vals) }),
+            }
+        }
+    }
+
+    /// Rendering values of a [`field::Container`] parsed as spaced
+    /// [`kind::Value`]s back into [`syn::Attribute`]'s arguments.
+    ///
+    /// Unlike [`ToArgs`], values are always wrapped into parentheses (like
+    /// `name(value)`), as the `name = value` form cannot be parsed back.
+    pub trait ToSpacedArgs<V>: field::Container<V> {
+        /// Renders values of this [`field::Container`] as [`syn::Attribute`]'s
+        /// arguments with the given `name`, pushing them into the provided
+        /// `args`.
+        fn to_spaced_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        );
+    }
+
+    impl<C, V> ToSpacedArgs<V> for C
+    where
+        C: field::Container<V> + ?Sized,
+        for<'a> &'a C: IntoIterator<Item = &'a V>,
+        V: ToArgTokens,
+    {
+        fn to_spaced_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        ) {
+            let vals = self
+                .into_iter()
+                .map(ToArgTokens::to_arg_tokens)
+                .collect::<Punctuated<_, token::Comma>>();
+            if !vals.is_empty() {
+                let name = syn::Ident::new(name, Span::call_site());
+                args.push(quote! { #name(#vals) });
+            }
+        }
+    }
+
+    impl<C, K, V> ToArgs<(K, V), kind::Map> for C
+    where
+        C: field::Container<(K, V)> + ?Sized,
+        for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+        K: ToArgTokens,
+        V: ToArgTokens,
+    {
+        fn to_args(
+            &self,
+            name: &str,
+            args: &mut Punctuated<TokenStream, token::Comma>,
+        ) {
+            let name = syn::Ident::new(name, Span::call_site());
+            for (k, v) in self {
+                let (k, v) = (k.to_arg_tokens(), v.to_arg_tokens());
+                args.push(quote! { #name #k = #v });
+            }
+        }
+    }
+
     /// [`TryApply`] and [`TryApplySelf`] traits' shim allowing to specify a
     /// parsing [`Kind`] and [`Dedup`]lication strategy as method's type
     /// parameters.
@@ -913,6 +1227,160 @@ pub mod dedup {
     impl Dedup for Last {}
 }
 
+pub mod schema {
+    //! Machine-readable description of an [`Attrs`]' arguments.
+    //!
+    //! [`Attrs`]: super::Attrs
+
+    use std::iter;
+
+    /// Description of all the [`syn::Attribute`]'s arguments parsed into an
+    /// [`Attrs`].
+    ///
+    /// [`Attrs`]: super::Attrs
+    #[derive(Clone, Debug, Default)]
+    pub struct Schema {
+        /// Descriptions of the [`syn::Attribute`]'s arguments, in the order of
+        /// the [`Attrs`]' fields declaration.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub args: Vec<Arg>,
+    }
+
+    impl Schema {
+        /// Renders a short human-readable usage summary of the described
+        /// [`Arg`]s, one [`Arg`] per line.
+        #[must_use]
+        pub fn help(&self) -> String {
+            if self.args.is_empty() {
+                return "no arguments are accepted".into();
+            }
+
+            let mut out = String::from("accepted arguments:");
+            for arg in &self.args {
+                out.push_str("\n- ");
+                out.push_str(
+                    &arg.names()
+                        .map(|n| format!("`{}`", arg.kind.usage(n)))
+                        .collect::<Vec<_>>()
+                        .join(" or "),
+                );
+                if arg.required {
+                    out.push_str(" (required)");
+                }
+                if let Some(summary) = arg.doc.and_then(|d| d.lines().next()) {
+                    out.push_str(": ");
+                    out.push_str(summary);
+                }
+            }
+            out
+        }
+    }
+
+    /// Description of a single [`syn::Attribute`]'s argument.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Arg {
+        /// Primary name of this [`Arg`].
+        pub name: &'static str,
+
+        /// Other names this [`Arg`] can be specified with.
+        pub aliases: &'static [&'static str],
+
+        /// [`Kind`] of this [`Arg`] parsing.
+        pub kind: Kind,
+
+        /// [`Dedup`]lication strategy of this [`Arg`] values.
+        pub dedup: Dedup,
+
+        /// Indicator whether this [`Arg`] is required to be specified.
+        pub required: bool,
+
+        /// Documentation of this [`Arg`], if any.
+        pub doc: Option<&'static str>,
+
+        /// Function returning [`Schema`] of the nested [`Attrs`], if this
+        /// [`Arg`] is of a [`Kind::Nested`].
+        ///
+        /// It's a function rather than a [`Schema`] to allow recursive
+        /// structures.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub nested: Option<fn() -> Schema>,
+    }
+
+    impl Arg {
+        /// Returns all the names this [`Arg`] can be specified with.
+        pub fn names(&self) -> impl Iterator<Item = &'static str> + use<> {
+            iter::once(self.name).chain(self.aliases.iter().copied())
+        }
+    }
+
+    /// Kind of an [`Arg`] parsing.
+    ///
+    /// Runtime counterpart of a [`kind::Kind`].
+    ///
+    /// [`kind::Kind`]: super::kind::Kind
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Kind {
+        /// [`Arg`] is parsed as a simple [`syn::Ident`].
+        ///
+        /// [`syn::Ident`]: struct@syn::Ident
+        Ident,
+
+        /// [`Arg`] is parsed as nested [`Attrs`].
+        ///
+        /// [`Attrs`]: super::Attrs
+        Nested,
+
+        /// [`Arg`] is parsed as values behind a [`syn::Ident`].
+        ///
+        /// [`syn::Ident`]: struct@syn::Ident
+        Value {
+            /// Indicator whether the value and the [`syn::Ident`] are separated
+            /// with spaces only.
+            ///
+            /// [`syn::Ident`]: struct@syn::Ident
+            spaced: bool,
+        },
+
+        /// [`Arg`] is parsed as key-value pairs behind a [`syn::Ident`].
+        ///
+        /// [`syn::Ident`]: struct@syn::Ident
+        Map,
+    }
+
+    impl Kind {
+        /// Renders usage of an [`Arg`] with the given `name` and this [`Kind`].
+        #[must_use]
+        pub fn usage(self, name: &str) -> String {
+            match self {
+                Self::Ident => name.into(),
+                Self::Nested => format!("{name}(...)"),
+                Self::Value { spaced: false } => format!("{name} = <value>"),
+                Self::Value { spaced: true } => format!("{name} <value>"),
+                Self::Map => format!("{name} <key> = <value>"),
+            }
+        }
+    }
+
+    /// Deduplication strategy of an [`Arg`] values.
+    ///
+    /// Runtime counterpart of a [`dedup::Dedup`].
+    ///
+    /// [`dedup::Dedup`]: super::dedup::Dedup
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Dedup {
+        /// Only a single value is allowed to appear.
+        Unique,
+
+        /// Only the first parsed value is picked.
+        First,
+
+        /// Only the last parsed value is picked.
+        Last,
+    }
+}
+
 pub mod validate {
     //! Validation machinery of an [`Attrs`]' field parsing.
     //!
@@ -1099,3 +1567,281 @@ pub mod validate {
         impl Rule for Provided {}
     }
 }
+
+pub mod tree {
+    //! Parsing of [`Attrs`] placed on a whole [`syn::DeriveInput`] in one pass.
+    //!
+    //! [`Attrs`]: super::Attrs
+
+    use super::{Attrs, Inherit};
+
+    /// [`Attrs`] parsed from a [`syn::DeriveInput`] along with the ones of its
+    /// variants and fields.
+    ///
+    /// ```rust
+    /// # use synthez::{ParseAttrs, parse::attrs::{Inherit, tree}, syn};
+    /// #
+    /// #[derive(Debug, Default, ParseAttrs)]
+    /// struct ContainerAttrs {
+    ///     #[parse(ident)]
+    ///     skip_all: Option<syn::Ident>,
+    /// }
+    ///
+    /// #[derive(Debug, Default, ParseAttrs)]
+    /// struct VariantAttrs {
+    ///     #[parse(value)]
+    ///     rename: Option<syn::LitStr>,
+    /// }
+    ///
+    /// #[derive(Debug, Default, ParseAttrs)]
+    /// struct FieldAttrs {
+    ///     #[parse(ident)]
+    ///     skip: Option<syn::Ident>,
+    /// }
+    ///
+    /// impl Inherit<ContainerAttrs> for FieldAttrs {
+    ///     fn inherit(&mut self, parent: &ContainerAttrs) -> syn::Result<()> {
+    ///         if self.skip.is_none() {
+    ///             self.skip = parent.skip_all.clone();
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let input: syn::DeriveInput = syn::parse_quote! {
+    ///     #[attr(skip_all)]
+    ///     enum Foo {
+    ///         #[attr(rename = "bar")]
+    ///         Bar { baz: u8 },
+    ///         Qux(u8),
+    ///     }
+    /// };
+    ///
+    /// let res =
+    ///     tree::Container::<ContainerAttrs, VariantAttrs, FieldAttrs>::parse(
+    ///         "attr", &input,
+    ///     );
+    /// let container = res.unwrap();
+    /// assert_eq!(container.variants.len(), 2);
+    /// assert!(container.variants[0].attrs.rename.is_some());
+    /// assert!(container.variants[1].fields[0].attrs.skip.is_some());
+    /// ```
+    ///
+    /// [`Attrs`]: super::Attrs
+    #[derive(Clone, Debug)]
+    pub struct Container<'a, C, V, F> {
+        /// [`syn::DeriveInput`] these [`Attrs`] are parsed from.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub input: &'a syn::DeriveInput,
+
+        /// [`Attrs`] placed on the [`syn::DeriveInput`] itself.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub attrs: C,
+
+        /// [`Field`]s of the [`syn::DeriveInput`], if it's a struct or an
+        /// union.
+        ///
+        /// Empty for enums.
+        pub fields: Vec<Field<'a, F>>,
+
+        /// [`Variant`]s of the [`syn::DeriveInput`], if it's an enum.
+        ///
+        /// Empty for structs and unions.
+        pub variants: Vec<Variant<'a, V, F>>,
+    }
+
+    impl<'a, C, V, F> Container<'a, C, V, F>
+    where
+        C: Attrs,
+        V: Attrs,
+        F: Attrs + Inherit<C>,
+    {
+        /// Parses [`syn::Attribute`]s with the given `name` placed on the
+        /// provided [`syn::DeriveInput`], its variants and fields.
+        ///
+        /// [`Field`]s' [`Attrs`] [`Inherit`] the container ones after being
+        /// parsed.
+        ///
+        /// # Errors
+        ///
+        /// If parsing of any [`Attrs`] or inheriting fails. Errors of all the
+        /// failed [`Attrs`] are combined into a single [`syn::Error`].
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub fn parse(
+            name: &str,
+            input: &'a syn::DeriveInput,
+        ) -> syn::Result<Self> {
+            let mut errs = None;
+            let attrs = collect(C::parse_attrs(name, input), &mut errs);
+
+            let (fields, variants) = match &input.data {
+                syn::Data::Struct(data) => (
+                    Field::parse_all(
+                        name,
+                        &data.fields,
+                        attrs.as_ref(),
+                        &mut errs,
+                    ),
+                    vec![],
+                ),
+                syn::Data::Enum(data) => (
+                    vec![],
+                    data.variants
+                        .iter()
+                        .filter_map(|variant| {
+                            let v_attrs = collect(
+                                V::parse_attrs(name, variant),
+                                &mut errs,
+                            );
+                            let fields = Field::parse_all(
+                                name,
+                                &variant.fields,
+                                attrs.as_ref(),
+                                &mut errs,
+                            );
+                            Some(Variant { variant, attrs: v_attrs?, fields })
+                        })
+                        .collect(),
+                ),
+                syn::Data::Union(data) => (
+                    data.fields
+                        .named
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, field)| {
+                            Field::parse(
+                                name,
+                                index,
+                                field,
+                                attrs.as_ref(),
+                                &mut errs,
+                            )
+                        })
+                        .collect(),
+                    vec![],
+                ),
+            };
+
+            errs.map_or_else(
+                || {
+                    let attrs = attrs.unwrap_or_default();
+                    Ok(Self { input, attrs, fields, variants })
+                },
+                Err,
+            )
+        }
+    }
+
+    impl<'a, C, V, F> Container<'a, C, V, F> {
+        /// Iterates over all the [`Field`]s of this [`Container`], including
+        /// the ones of its [`Variant`]s.
+        pub fn all_fields(&self) -> impl Iterator<Item = &Field<'a, F>> {
+            self.fields
+                .iter()
+                .chain(self.variants.iter().flat_map(|v| &v.fields))
+        }
+    }
+
+    /// [`Attrs`] parsed from a [`syn::Variant`] along with the ones of its
+    /// fields.
+    ///
+    /// [`Attrs`]: super::Attrs
+    #[derive(Clone, Debug)]
+    pub struct Variant<'a, V, F> {
+        /// [`syn::Variant`] these [`Attrs`] are parsed from.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub variant: &'a syn::Variant,
+
+        /// [`Attrs`] placed on the [`syn::Variant`] itself.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub attrs: V,
+
+        /// [`Field`]s of the [`syn::Variant`].
+        pub fields: Vec<Field<'a, F>>,
+    }
+
+    /// [`Attrs`] parsed from a [`syn::Field`].
+    ///
+    /// [`Attrs`]: super::Attrs
+    #[derive(Clone, Debug)]
+    pub struct Field<'a, F> {
+        /// [`syn::Field`] these [`Attrs`] are parsed from.
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub field: &'a syn::Field,
+
+        /// Index of the [`syn::Field`] in its struct or variant.
+        pub index: usize,
+
+        /// [`Attrs`] placed on the [`syn::Field`].
+        ///
+        /// [`Attrs`]: super::Attrs
+        pub attrs: F,
+    }
+
+    impl<'a, F: Attrs> Field<'a, F> {
+        /// Parses [`Attrs`] of all the given [`syn::Fields`], collecting
+        /// errors into the provided `errs`.
+        ///
+        /// [`Attrs`]: super::Attrs
+        fn parse_all<C>(
+            name: &str,
+            fields: &'a syn::Fields,
+            parent: Option<&C>,
+            errs: &mut Option<syn::Error>,
+        ) -> Vec<Self>
+        where
+            F: Inherit<C>,
+        {
+            fields
+                .iter()
+                .enumerate()
+                .filter_map(|(index, field)| {
+                    Self::parse(name, index, field, parent, errs)
+                })
+                .collect()
+        }
+
+        /// Parses [`Attrs`] of the given [`syn::Field`] and makes them
+        /// [`Inherit`] the `parent` ones, if any, collecting errors into the
+        /// provided `errs`.
+        ///
+        /// [`Attrs`]: super::Attrs
+        fn parse<C>(
+            name: &str,
+            index: usize,
+            field: &'a syn::Field,
+            parent: Option<&C>,
+            errs: &mut Option<syn::Error>,
+        ) -> Option<Self>
+        where
+            F: Inherit<C>,
+        {
+            let res = F::parse_attrs(name, field).and_then(|mut attrs| {
+                if let Some(parent) = parent {
+                    attrs.inherit(parent)?;
+                }
+                Ok(attrs)
+            });
+            collect(res, errs).map(|attrs| Self { field, index, attrs })
+        }
+    }
+
+    /// Unwraps the given [`syn::Result`], combining its error into the
+    /// provided `errs`, if any.
+    fn collect<T>(
+        res: syn::Result<T>,
+        errs: &mut Option<syn::Error>,
+    ) -> Option<T> {
+        res.map_err(|err| match errs {
+            Some(errs) => errs.combine(err),
+            None => *errs = Some(err),
+        })
+        .ok()
+    }
+}
@@ -23,6 +23,27 @@ pub fn unknown_attr_arg<S: IntoSpan>(span: S, name: &str) -> syn::Error {
     )
 }
 
+/// Creates an "unknown value" [`syn::Error`] for the given `value` pointing to
+/// the given [`Span`] and listing the `expected` values.
+///
+/// [`Span`]: proc_macro2::Span
+#[must_use]
+pub fn unknown_value<S: IntoSpan>(
+    span: S,
+    value: &str,
+    expected: &[&str],
+) -> syn::Error {
+    let expected =
+        expected.iter().map(|v| format!("`{v}`")).collect::<Vec<_>>();
+    syn::Error::new(
+        span.into_span(),
+        format!(
+            "unknown value `{value}`, expected one of: {}",
+            expected.join(", "),
+        ),
+    )
+}
+
 /// Creates an "expected followed by comma" [`syn::Error`] in the given
 /// [`Span`].
 ///
@@ -31,3 +52,13 @@ pub fn unknown_attr_arg<S: IntoSpan>(span: S, name: &str) -> syn::Error {
 pub fn expected_followed_by_comma<S: IntoSpan>(span: S) -> syn::Error {
     syn::Error::new(span.into_span(), "expected followed by `,`")
 }
+
+/// Attaches the given `help` message to the provided [`syn::Error`], pointing
+/// to the same [`Span`].
+///
+/// [`Span`]: proc_macro2::Span
+#[must_use]
+pub fn with_help(mut err: syn::Error, help: &str) -> syn::Error {
+    err.combine(syn::Error::new(err.span(), format!("help: {help}")));
+    err
+}
@@ -4,10 +4,10 @@
 
 use std::{any::TypeId, iter};
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenTree};
 use sealed::sealed;
 use syn::{
-    parse::Parse,
+    parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::{self, Token},
 };
@@ -59,6 +59,47 @@ pub trait ParseBuffer {
         self.parse_any_ident().map(drop)
     }
 
+    /// Skips the next attribute's argument along with its arbitrary body (like
+    /// `name`, `name = value` or `name(...)`), up to the next top-level
+    /// [`token::Comma`], which is left unparsed.
+    ///
+    /// Groups are skipped as a whole, so commas inside them don't stop the
+    /// skipping, while top-level ones (like in `name = Foo<A, B>`) do.
+    ///
+    /// # Errors
+    ///
+    /// Never, as any [`Token`]s are accepted.
+    ///
+    /// [`token::Comma`]: struct@token::Comma
+    fn skip_any_arg(&self) -> syn::Result<()>;
+
+    /// Parses the next [`Token`]s as `T`, allowing them to be wrapped into a
+    /// string literal (like `"my::module"`), in which case its contents are
+    /// parsed with the literal's [`Span`].
+    ///
+    /// Always moves [`ParseBuffer`]'s cursor.
+    ///
+    /// # Errors
+    ///
+    /// If `T` fails to be parsed.
+    fn parse_maybe_str<T: Parse>(&self) -> syn::Result<T> {
+        self.parse_maybe_str_with(T::parse)
+    }
+
+    /// Parses the next [`Token`]s as `T` with the provided `parser` function,
+    /// allowing them to be wrapped into a string literal (like `"my::module"`),
+    /// in which case its contents are parsed with the literal's [`Span`].
+    ///
+    /// Always moves [`ParseBuffer`]'s cursor.
+    ///
+    /// # Errors
+    ///
+    /// If `T` fails to be parsed.
+    fn parse_maybe_str_with<T>(
+        &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
+    ) -> syn::Result<T>;
+
     /// Parses the wrapped (in a wrapper `W`) [`Token`]s as `T` [`Punctuated`]
     /// with a `P` separator.
     ///
@@ -72,6 +113,26 @@ pub trait ParseBuffer {
     ) -> syn::Result<Punctuated<T, P>>
     where
         T: Parse,
+        W: Default + Token + AcceptedWrapper + 'static,
+        P: Default + Parse + Token,
+    {
+        self.parse_wrapped_and_punctuated_with::<T, W, P>(T::parse)
+    }
+
+    /// Parses the wrapped (in a wrapper `W`) [`Token`]s as `T` [`Punctuated`]
+    /// with a `P` separator, using the provided `parser` function for parsing
+    /// `T`.
+    ///
+    /// Always moves [`ParseBuffer`]'s cursor.
+    ///
+    /// # Errors
+    ///
+    /// If parsing [`Punctuated`] `T` wrapped into `W` fails.
+    fn parse_wrapped_and_punctuated_with<T, W, P>(
+        &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
+    ) -> syn::Result<Punctuated<T, P>>
+    where
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token;
 
@@ -90,6 +151,28 @@ pub trait ParseBuffer {
     ) -> syn::Result<Punctuated<T, P>>
     where
         T: Parse,
+        W: Default + Token + AcceptedWrapper + 'static,
+        P: Default + Parse + Token,
+    {
+        self.parse_maybe_wrapped_and_punctuated_with::<T, W, P>(T::parse)
+    }
+
+    /// Checks whether the next [`Token`] is a wrapper `W` and if yes, then
+    /// parses the wrapped [`Token`]s as `T` [`Punctuated`] with a `P`
+    /// separator. Otherwise, parses just `T`. Uses the provided `parser`
+    /// function for parsing `T`.
+    ///
+    /// Always moves [`ParseBuffer`]'s cursor.
+    ///
+    /// # Errors
+    ///
+    /// If either parsing [`Punctuated`] `T` wrapped into `W`, or parsing just
+    /// `T`, fails.
+    fn parse_maybe_wrapped_and_punctuated_with<T, W, P>(
+        &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
+    ) -> syn::Result<Punctuated<T, P>>
+    where
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token;
 
@@ -110,6 +193,30 @@ pub trait ParseBuffer {
     ) -> syn::Result<Punctuated<T, P>>
     where
         T: Parse,
+        W: Default + Token + AcceptedWrapper + 'static,
+        P: Default + Parse + Token,
+    {
+        self.parse_eq_or_wrapped_and_punctuated_with::<T, W, P>(T::parse)
+    }
+
+    /// Checks whether the next [`Token`] is a wrapper `W` and if yes, then
+    /// parses the wrapped [`Token`]s as `T` [`Punctuated`] with a `P`
+    /// separator. Otherwise, parses just `T` following the [`token::Eq`]. Uses
+    /// the provided `parser` function for parsing `T`.
+    ///
+    /// Always moves [`ParseBuffer`]'s cursor.
+    ///
+    /// # Errors
+    ///
+    /// If either parsing [`Punctuated`] `T` wrapped into `W`, or parsing just
+    /// `T` following the [`token::Eq`], fails.
+    ///
+    /// [`token::Eq`]: struct@token::Eq
+    fn parse_eq_or_wrapped_and_punctuated_with<T, W, P>(
+        &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
+    ) -> syn::Result<Punctuated<T, P>>
+    where
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token;
 }
@@ -128,11 +235,35 @@ impl ParseBuffer for syn::parse::ParseBuffer<'_> {
         <syn::Ident as syn::ext::IdentExt>::parse_any(self)
     }
 
-    fn parse_wrapped_and_punctuated<T, W, P>(
+    fn skip_any_arg(&self) -> syn::Result<()> {
+        self.step(|cursor| {
+            let mut rest = *cursor;
+            while let Some((tt, next)) = rest.token_tree() {
+                if matches!(&tt, TokenTree::Punct(p) if p.as_char() == ',') {
+                    break;
+                }
+                rest = next;
+            }
+            Ok(((), rest))
+        })
+    }
+
+    fn parse_maybe_str_with<T>(
         &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
+    ) -> syn::Result<T> {
+        if self.peek(syn::LitStr) {
+            self.parse::<syn::LitStr>()?.parse_with(parser)
+        } else {
+            parser(self)
+        }
+    }
+
+    fn parse_wrapped_and_punctuated_with<T, W, P>(
+        &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
     ) -> syn::Result<Punctuated<T, P>>
     where
-        T: Parse,
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token,
     {
@@ -150,37 +281,37 @@ impl ParseBuffer for syn::parse::ParseBuffer<'_> {
                  brackets, braces and parentheses as wrappers.",
             ));
         }
-        Punctuated::parse_terminated(&inner)
+        Punctuated::parse_terminated_with(&inner, parser)
     }
 
-    fn parse_maybe_wrapped_and_punctuated<T, W, P>(
+    fn parse_maybe_wrapped_and_punctuated_with<T, W, P>(
         &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
     ) -> syn::Result<Punctuated<T, P>>
     where
-        T: Parse,
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token,
     {
         Ok(if self.is_next::<W>() {
-            self.parse_wrapped_and_punctuated::<T, W, P>()?
+            self.parse_wrapped_and_punctuated_with::<T, W, P>(parser)?
         } else {
-            iter::once(self.parse::<T>()?).collect()
+            iter::once(parser(self)?).collect()
         })
     }
 
-    fn parse_eq_or_wrapped_and_punctuated<T, W, P>(
+    fn parse_eq_or_wrapped_and_punctuated_with<T, W, P>(
         &self,
+        parser: fn(ParseStream<'_>) -> syn::Result<T>,
     ) -> syn::Result<Punctuated<T, P>>
     where
-        T: Parse,
         W: Default + Token + AcceptedWrapper + 'static,
         P: Default + Parse + Token,
     {
         Ok(if self.is_next::<W>() {
-            self.parse_wrapped_and_punctuated::<T, W, P>()?
+            self.parse_wrapped_and_punctuated_with::<T, W, P>(parser)?
         } else {
             _ = self.parse::<token::Eq>()?;
-            iter::once(self.parse::<T>()?).collect()
+            iter::once(parser(self)?).collect()
         })
     }
 }
@@ -0,0 +1,53 @@
+//! Machinery for parsing fieldless enums out of identifiers (like
+//! `mode = strict`).
+
+use syn::{ext::IdentExt as _, parse::ParseStream};
+
+use crate::{parse::err, spanned::Spanning};
+
+/// Fieldless enum parsed out of an identifier (or a string literal, if
+/// [`IdentEnum::FROM_STR`] is enabled), representing one of its variants.
+///
+/// Usually implemented via `#[derive(ParseIdentEnum)]` along with
+/// [`syn::parse::Parse`], [`ToTokens`] and [`Display`] implementations.
+///
+/// [`Display`]: std::fmt::Display
+/// [`ToTokens`]: quote::ToTokens
+pub trait IdentEnum: Sized {
+    /// Values accepted for parsing, in the order of the enum variants.
+    const VALUES: &'static [&'static str];
+
+    /// Indicator whether the values may be also specified as string literals.
+    const FROM_STR: bool = false;
+
+    /// Returns the enum variant represented by the given `value`, if any.
+    #[must_use]
+    fn from_value(value: &str) -> Option<Self>;
+
+    /// Returns the value representing this enum variant.
+    #[must_use]
+    fn value(&self) -> &'static str;
+
+    /// Parses this enum variant out of the given [`ParseStream`], along with
+    /// its [`Span`].
+    ///
+    /// # Errors
+    ///
+    /// - If neither identifier nor string literal (if [`IdentEnum::FROM_STR`]
+    ///   is enabled) can be parsed.
+    /// - If the parsed value is not one of [`IdentEnum::VALUES`].
+    ///
+    /// [`Span`]: proc_macro2::Span
+    fn parse_spanned(input: ParseStream<'_>) -> syn::Result<Spanning<Self>> {
+        let (value, span) = if Self::FROM_STR && input.peek(syn::LitStr) {
+            let lit = input.parse::<syn::LitStr>()?;
+            (lit.value(), lit.span())
+        } else {
+            let ident = input.call(syn::Ident::parse_any)?;
+            (ident.unraw().to_string(), ident.span())
+        };
+        Self::from_value(&value)
+            .map(|v| Spanning::new(v, span))
+            .ok_or_else(|| err::unknown_value(span, &value, Self::VALUES))
+    }
+}
@@ -4,6 +4,9 @@ pub mod attr;
 pub mod attrs;
 pub mod err;
 pub mod ext;
+pub mod ident_enum;
 
 #[doc(inline)]
-pub use self::{attrs::Attrs, ext::ParseBuffer as BufferExt};
+pub use self::{
+    attrs::Attrs, ext::ParseBuffer as BufferExt, ident_enum::IdentEnum,
+};
@@ -1,10 +1,19 @@
 //! Batteries for [`Span`] and [`syn::spanned`].
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
 
-use proc_macro2::Span;
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::ToTokens;
 use sealed::sealed;
-use syn::spanned::Spanned;
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
 
 /// Helper coercion for [`Span`] and [`Spanned`] types to use in function
 /// arguments.
@@ -39,7 +48,94 @@ impl<T> IntoSpan for &Spanning<T> {
     }
 }
 
+#[sealed]
+impl IntoSpan for syn::Error {
+    #[inline]
+    fn into_span(self) -> Span {
+        self.span()
+    }
+}
+
+#[sealed]
+impl IntoSpan for TokenTree {
+    #[inline]
+    fn into_span(self) -> Span {
+        self.span()
+    }
+}
+
+/// Coerces into [`Span::call_site()`] if [`None`].
+#[sealed]
+impl<T: ?Sized> IntoSpan for Option<&T>
+where
+    for<'a> &'a T: IntoSpan,
+{
+    #[inline]
+    fn into_span(self) -> Span {
+        self.map_or_else(Span::call_site, IntoSpan::into_span)
+    }
+}
+
+/// Joins the given [`Span`]s into a single one covering both of them.
+///
+/// Falls back to the `first` [`Span`] if joining is not available (like on
+/// stable Rust inside a proc macro) or the [`Span`]s are located in different
+/// files.
+#[must_use]
+pub fn join<A: IntoSpan, B: IntoSpan>(first: A, second: B) -> Span {
+    let first = first.into_span();
+    first.join(second.into_span()).unwrap_or(first)
+}
+
+/// Relocates all the [`TokenTree`]s of the given [`TokenStream`] to the
+/// provided [`Span`].
+///
+/// Descends into groups too, so compiler errors about any of the tokens point
+/// to the provided [`Span`] instead of, for example, [`Span::call_site()`].
+///
+/// Hygiene of the relocated [`TokenTree`]s is preserved (see
+/// [`Span::located_at()`]), so identifiers resolve the same way as before.
+///
+/// ```rust
+/// # use synthez::{proc_macro2::Span, quote::quote, spanned};
+/// #
+/// let tokens = spanned::respan(quote! { foo(bar) }, Span::mixed_site());
+///
+/// assert_eq!(tokens.to_string(), "foo (bar)");
+/// ```
+#[must_use]
+pub fn respan<S: IntoSpan>(tokens: TokenStream, span: S) -> TokenStream {
+    let span = span.into_span();
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(g) = &tt {
+                let mut group =
+                    Group::new(g.delimiter(), respan(g.stream(), span));
+                group.set_span(g.span().located_at(span));
+                tt = TokenTree::Group(group);
+            } else {
+                tt.set_span(tt.span().located_at(span));
+            }
+            tt
+        })
+        .collect()
+}
+
 /// Wrapper for non-[`Spanned`] types to hold their [`Span`].
+///
+/// Comparison, hashing and formatting ignore the held [`Span`], so a
+/// [`Spanning`] value may be used as a key of sets and maps.
+///
+/// [`ToTokens`] is intentionally not implemented, as it would make a
+/// [`Spanning`] value [`Spanned`] through its tokens, conflicting with the
+/// [`IntoSpan`] implementation returning the held [`Span`] (which is required
+/// for non-[`ToTokens`] values too). Use
+/// [`Spanning::to_respanned_tokens()`] instead. Still, a [`Spanning`] value is
+/// rendered by [`ToArgs`] as the wrapped one with the held [`Span`]
+/// re-applied.
+///
+/// [`ToArgs`]: crate::parse::attrs::field::ToArgs
 #[derive(Clone, Copy, Debug)]
 pub struct Spanning<T: ?Sized> {
     /// [`Span`] of the `item`.
@@ -62,6 +158,54 @@ impl<T> Spanning<T> {
     pub fn into_inner(self) -> T {
         self.item
     }
+
+    /// Maps the underlying value of this [`Spanning`] wrapper with the given
+    /// function, preserving its [`Span`].
+    #[must_use]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Spanning<U> {
+        Spanning { span: self.span, item: f(self.item) }
+    }
+
+    /// Maps the underlying value of this [`Spanning`] wrapper with the given
+    /// fallible function, preserving its [`Span`].
+    ///
+    /// # Errors
+    ///
+    /// If the given function fails.
+    pub fn try_map<U, E>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> Result<Spanning<U>, E> {
+        Ok(Spanning { span: self.span, item: f(self.item)? })
+    }
+
+    /// Replaces the [`Span`] of this [`Spanning`] wrapper with the given one.
+    #[must_use]
+    pub fn with_span<S: IntoSpan>(self, span: S) -> Self {
+        Self { span: span.into_span(), item: self.item }
+    }
+
+    /// Joins two [`Spanning`] wrappers into a single one, holding both their
+    /// values and covering both their [`Span`]s.
+    ///
+    /// See [`join()`] for details of how [`Span`]s are joined.
+    ///
+    /// ```rust
+    /// # use synthez::{Spanning, proc_macro2::Span};
+    /// #
+    /// let name = Spanning::new("foo", Span::call_site());
+    /// let len = name.as_ref().map(|s| s.len());
+    ///
+    /// let joined = Spanning::join(name, len);
+    /// assert_eq!(*joined, ("foo", 3));
+    /// ```
+    #[must_use]
+    pub fn join<U>(first: Self, second: Spanning<U>) -> Spanning<(T, U)> {
+        Spanning {
+            span: join(first.span, second.span),
+            item: (first.item, second.item),
+        }
+    }
 }
 
 impl<T: ?Sized> Spanning<T> {
@@ -70,6 +214,45 @@ impl<T: ?Sized> Spanning<T> {
     pub const fn span(&self) -> Span {
         self.span
     }
+
+    /// Borrows the underlying value of this [`Spanning`] wrapper, preserving
+    /// its [`Span`].
+    #[must_use]
+    pub const fn as_ref(&self) -> Spanning<&T> {
+        Spanning { span: self.span, item: &self.item }
+    }
+
+    /// Dereferences the underlying value of this [`Spanning`] wrapper,
+    /// preserving its [`Span`].
+    #[must_use]
+    pub fn as_deref(&self) -> Spanning<&T::Target>
+    where
+        T: Deref,
+    {
+        Spanning { span: self.span, item: &*self.item }
+    }
+
+    /// Generates a [`TokenStream`] out of the underlying value with the given
+    /// function, and relocates it to the [`Span`] of this [`Spanning`] wrapper
+    /// (see [`respan()`]), in the manner of [`quote_spanned!`].
+    ///
+    /// ```rust
+    /// # use synthez::{Spanning, proc_macro2::Span, quote::quote};
+    /// #
+    /// let name = Spanning::new("foo", Span::call_site());
+    /// let tokens = name.quote_spanned(|n| quote! { let _ = #n; });
+    ///
+    /// assert_eq!(tokens.to_string(), "let _ = \"foo\" ;");
+    /// ```
+    ///
+    /// [`quote_spanned!`]: quote::quote_spanned
+    #[must_use]
+    pub fn quote_spanned(
+        &self,
+        f: impl FnOnce(&T) -> TokenStream,
+    ) -> TokenStream {
+        respan(f(&self.item), self.span)
+    }
 }
 
 impl<T: ?Sized> Deref for Spanning<T> {
@@ -98,6 +281,66 @@ where
 
 impl<T: PartialEq + ?Sized> Eq for Spanning<T> {}
 
+impl<T, V> PartialOrd<Spanning<V>> for Spanning<T>
+where
+    T: PartialOrd<V> + ?Sized,
+    V: ?Sized,
+{
+    fn partial_cmp(&self, other: &Spanning<V>) -> Option<Ordering> {
+        self.item.partial_cmp(&other.item)
+    }
+}
+
+impl<T: Ord + ?Sized> Ord for Spanning<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.item.cmp(&other.item)
+    }
+}
+
+impl<T: Hash + ?Sized> Hash for Spanning<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.item.hash(state);
+    }
+}
+
+impl<T: fmt::Display + ?Sized> fmt::Display for Spanning<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.item.fmt(f)
+    }
+}
+
+impl<T: Parse> Parse for Spanning<T> {
+    /// Parses the underlying value, capturing the [`Span`] of all its parsed
+    /// tokens.
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let start = input.cursor();
+        let item = input.parse()?;
+        let end = input.cursor();
+
+        let mut span = start.span();
+        let mut cursor = start;
+        while cursor < end {
+            let Some((tt, next)) = cursor.token_tree() else { break };
+            span = join(span, tt.span());
+            cursor = next;
+        }
+        Ok(Self { span, item })
+    }
+}
+
+impl<T: ToTokens + ?Sized> Spanning<T> {
+    /// Renders the underlying value into a [`TokenStream`], re-applying the
+    /// [`Span`] of this [`Spanning`] wrapper to all the rendered tokens.
+    ///
+    /// Use it to interpolate a [`Spanning`] value into [`quote!`] output.
+    ///
+    /// [`quote!`]: quote::quote
+    #[must_use]
+    pub fn to_respanned_tokens(&self) -> TokenStream {
+        respan(self.item.to_token_stream(), self.span)
+    }
+}
+
 impl From<Spanning<&str>> for syn::LitStr {
     fn from(s: Spanning<&str>) -> Self {
         Self::new(s.item, s.span)
@@ -157,7 +157,7 @@ mod for_docs_only {
 #[doc(inline)]
 pub use synthez_codegen::ToTokens;
 #[doc(inline)]
-pub use synthez_core::{ext, field, has, spanned};
+pub use synthez_core::{case, ext, field, has, ident, spanned};
 pub use synthez_core::{
     proc_macro2,
     quote::{self, ToTokens},
@@ -166,9 +166,15 @@ pub use synthez_core::{
 
 #[doc(inline)]
 pub use self::{
-    ext::{Data as DataExt, Ident as IdentExt},
+    ext::{
+        Data as DataExt, Fields as FieldsExt, Generics as GenericsExt,
+        Ident as IdentExt, Type as TypeExt,
+    },
     field::Required,
-    parse::{Attrs as ParseAttrs, BufferExt as ParseBufferExt},
+    parse::{
+        Attrs as ParseAttrs, BufferExt as ParseBufferExt,
+        IdentEnum as ParseIdentEnum,
+    },
     spanned::Spanning,
 };
 
@@ -179,7 +185,9 @@ pub mod parse {
     pub use synthez_core::parse::{attr, err, ext};
 
     #[doc(inline)]
-    pub use self::{attrs::Attrs, ext::ParseBuffer as BufferExt};
+    pub use self::{
+        attrs::Attrs, ext::ParseBuffer as BufferExt, ident_enum::IdentEnum,
+    };
 
     pub mod attrs {
         //! Machinery for parsing [`syn::Attribute`]s into a custom defined
@@ -192,4 +200,14 @@ pub mod parse {
         #[doc(inline)]
         pub use synthez_core::parse::attrs::*;
     }
+
+    pub mod ident_enum {
+        //! Machinery for parsing fieldless enums out of identifiers (like
+        //! `mode = strict`).
+
+        #[doc(inline)]
+        pub use synthez_codegen::ParseIdentEnum as IdentEnum;
+        #[doc(inline)]
+        pub use synthez_core::parse::ident_enum::*;
+    }
 }
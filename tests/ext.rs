@@ -0,0 +1,177 @@
+#![forbid(non_ascii_idents, unsafe_code)]
+
+mod generics {
+    mod with_fresh_type_param {
+        use synthez::{GenericsExt as _, ident, quote::quote, syn};
+
+        fn fresh(generics: &syn::Generics) -> String {
+            let input: syn::DeriveInput = syn::parse_quote! {
+                struct Foo #generics;
+            };
+            let mut idents = ident::Factory::new(&input);
+            let (generics, ident) =
+                generics.with_fresh_type_param(&mut idents, "U");
+            assert_eq!(ident.to_string(), "U");
+            quote! { #generics }.to_string()
+        }
+
+        #[test]
+        fn appends_to_params() {
+            let generics = syn::parse_quote! { <'a, T> };
+
+            assert_eq!(fresh(&generics), quote! { <'a, T, U> }.to_string());
+        }
+
+        #[test]
+        fn inserts_before_defaulted_type_param() {
+            let generics = syn::parse_quote! { <'a, T, V = u8> };
+
+            assert_eq!(
+                fresh(&generics),
+                quote! { <'a, T, U, V = u8> }.to_string(),
+            );
+        }
+
+        #[test]
+        fn inserts_before_defaulted_const_param() {
+            let generics = syn::parse_quote! { <T, const N: usize = 1> };
+
+            assert_eq!(
+                fresh(&generics),
+                quote! { <T, U, const N: usize = 1> }.to_string(),
+            );
+        }
+
+        #[test]
+        fn keeps_lifetimes_first() {
+            let generics = syn::parse_quote! { <'a, T = u8> };
+
+            assert_eq!(
+                fresh(&generics),
+                quote! { <'a, U, T = u8> }.to_string()
+            );
+        }
+    }
+
+    mod usage_in {
+        use synthez::{GenericsExt as _, syn};
+
+        #[test]
+        fn detects_params() {
+            let generics: syn::Generics = syn::parse_quote! {
+                <'a, 'b, T, U, const N: usize>
+            };
+            let ty = syn::parse_quote! { &'a (Vec<T>, Option<&'a T>) };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["T", "T"]);
+            assert!(usage.const_params.is_empty());
+            assert_eq!(usage.lifetimes.len(), 2);
+            assert!(usage.lifetimes.iter().all(|lt| lt.ident == "a"));
+            assert!(usage.projections.is_empty());
+        }
+
+        #[test]
+        fn skips_lifetimes_bound_by_fn_pointer() {
+            let generics: syn::Generics = syn::parse_quote! { <'a, U> };
+            let ty = syn::parse_quote! { for<'a> fn(&'a U) -> &'a U };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["U", "U"]);
+            assert!(usage.lifetimes.is_empty());
+        }
+
+        #[test]
+        fn skips_lifetimes_bound_by_trait_bound() {
+            let generics: syn::Generics = syn::parse_quote! { <'a, 'b, U> };
+            let ty = syn::parse_quote! {
+                Box<dyn for<'a> Fn(&'a U) -> &'b U + 'a>
+            };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["U", "U"]);
+            assert_eq!(usage.lifetimes.len(), 2);
+            assert_eq!(usage.lifetimes[0].ident, "b");
+            assert_eq!(usage.lifetimes[1].ident, "a");
+        }
+
+        #[test]
+        fn detects_lifetimes_outside_binder() {
+            let generics: syn::Generics = syn::parse_quote! { <'a> };
+            let ty = syn::parse_quote! { (for<'a> fn(&'a str), &'a str) };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.lifetimes.len(), 1);
+            assert_eq!(usage.lifetimes[0].ident, "a");
+        }
+
+        #[test]
+        fn detects_qualified_projections() {
+            let generics: syn::Generics = syn::parse_quote! { <T, U> };
+            let ty = syn::parse_quote! { Vec<<T as IntoIterator>::Item> };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["T"]);
+            assert_eq!(
+                usage.projections,
+                [syn::parse_quote! { <T as IntoIterator>::Item }],
+            );
+        }
+
+        #[test]
+        fn detects_projections() {
+            let generics: syn::Generics = syn::parse_quote! { <T, U> };
+            let ty = syn::parse_quote! { HashMap<T::Key, T::Value> };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["T", "T"]);
+            assert_eq!(
+                usage.projections,
+                [syn::parse_quote! { T::Key }, syn::parse_quote! { T::Value },],
+            );
+        }
+
+        #[test]
+        fn skips_projections_on_non_params() {
+            let generics: syn::Generics = syn::parse_quote! { <T> };
+            let ty =
+                syn::parse_quote! { (<Vec<T> as IntoIterator>::Item, X::Y) };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.type_params, ["T"]);
+            assert!(usage.projections.is_empty());
+        }
+
+        #[test]
+        fn detects_const_params_in_array_length() {
+            let generics: syn::Generics = syn::parse_quote! {
+                <T, const N: usize, const M: usize>
+            };
+            let ty = syn::parse_quote! { [[u8; N]; { M * 2 }] };
+
+            let usage = generics.usage_in(&ty);
+
+            assert!(usage.type_params.is_empty());
+            assert_eq!(usage.const_params, ["N", "M"]);
+        }
+
+        #[test]
+        fn detects_const_params_in_generic_args() {
+            let generics: syn::Generics = syn::parse_quote! {
+                <const N: usize>
+            };
+            let ty = syn::parse_quote! { Foo<N, { N + 1 }> };
+
+            let usage = generics.usage_in(&ty);
+
+            assert_eq!(usage.const_params, ["N", "N"]);
+        }
+    }
+}
@@ -2413,3 +2413,1546 @@ mod nested {
         }
     }
 }
+
+mod to_tokens {
+    use std::collections::BTreeMap;
+
+    use synthez::{
+        ParseAttrs, Required, Spanning, ToTokens as _, quote::quote, syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(to_tokens)]
+    struct Sub {
+        #[parse(ident)]
+        ignore: Option<syn::Ident>,
+
+        #[parse(value)]
+        name: Option<syn::LitStr>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(to_tokens)]
+    struct Attr {
+        #[parse(ident, args(skip, omit))]
+        skip: Option<syn::Ident>,
+
+        #[parse(value)]
+        rename: Required<syn::LitStr>,
+
+        #[parse(value(spaced))]
+        with: Vec<syn::Expr>,
+
+        #[parse(value)]
+        alias: Vec<syn::LitStr>,
+
+        #[parse(map)]
+        ty: BTreeMap<syn::Ident, syn::Type>,
+
+        #[parse(nested)]
+        sub: Option<Spanning<Sub>>,
+    }
+
+    #[test]
+    fn renders_all_kinds() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(omit, rename = "x", with 1 + 2, with(3))]
+            #[attr(alias = "a", alias("b"))]
+            #[attr(ty A = Option<u8>, ty B = u16)]
+            #[attr(sub(ignore, name = "y"))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().to_token_stream().to_string(),
+            quote! {
+                omit,
+                rename = "x",
+                with(1 + 2, 3),
+                alias("a", "b"),
+                ty A = Option<u8>,
+                ty B = u16,
+                sub(ignore, name = "y")
+            }
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn omits_absent() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(rename = "x")]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().to_token_stream().to_string(),
+            quote! { rename = "x" }.to_string(),
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(skip, rename = "x", with 1, ty A = u8, sub(name = "y"))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        let parsed = res.unwrap();
+
+        let attr = parsed.to_attribute("attr");
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #attr
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().to_token_stream().to_string(),
+            parsed.to_token_stream().to_string(),
+        );
+    }
+}
+
+mod builder {
+    use std::collections::BTreeMap;
+
+    use synthez::{
+        IdentExt as _, ParseAttrs, Required, Spanning, proc_macro2::Span, syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(builder)]
+    struct Sub {
+        #[parse(ident)]
+        ignore: Option<syn::Ident>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(builder)]
+    struct Attr {
+        #[parse(value, validate = not_foo)]
+        rename: Required<syn::LitStr>,
+
+        #[parse(value)]
+        with: Vec<syn::Expr>,
+
+        #[parse(map)]
+        ty: BTreeMap<syn::Ident, syn::Type>,
+
+        #[parse(nested)]
+        sub: Option<Spanning<Sub>>,
+    }
+
+    fn not_foo(lit: &Required<syn::LitStr>) -> syn::Result<()> {
+        if lit.value() == "foo" {
+            Err(syn::Error::new(lit.span(), "'foo' is not allowed"))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn builds() {
+        let sub = Sub::builder()
+            .ignore(syn::Ident::new_on_call_site("ignore"))
+            .build("attr");
+        assert!(sub.is_ok(), "failed: {}", sub.unwrap_err());
+
+        let res = Attr::builder()
+            .rename(syn::parse_quote!("bar"))
+            .with(syn::parse_quote!(1))
+            .with(syn::parse_quote!(2))
+            .ty((syn::parse_quote!(A), syn::parse_quote!(u8)))
+            .sub(Spanning::new(sub.unwrap(), Span::call_site()))
+            .build("attr");
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let out = res.unwrap();
+        assert_eq!(out.rename.value(), "bar");
+        assert_eq!(out.with.len(), 2);
+        assert_eq!(out.ty[&syn::parse_quote!(A)], syn::parse_quote!(u8));
+        assert_eq!(
+            out.sub.unwrap().ignore,
+            Some(syn::Ident::new_on_call_site("ignore")),
+        );
+    }
+
+    #[test]
+    fn forbids_absent_required() {
+        let res = Attr::builder().with(syn::parse_quote!(1)).build("attr");
+        assert!(res.is_err(), "should fail, but is ok");
+
+        let err = res.unwrap_err().to_string();
+        assert!(
+            err.contains(
+                "`rename` argument of `#[attr]` attribute is expected"
+            ),
+            "wrong err:\n{err}",
+        );
+    }
+
+    #[test]
+    fn runs_custom_validation() {
+        let res =
+            Attr::builder().rename(syn::parse_quote!("foo")).build("attr");
+        assert!(res.is_err(), "should fail, but is ok");
+
+        let err = res.unwrap_err().to_string();
+        assert_eq!(err, "'foo' is not allowed");
+    }
+
+    #[test]
+    fn errors_on_reserved_field_names() {
+        for input in [
+            syn::parse_quote! {
+                #[parse(builder)]
+                struct Attr {
+                    #[parse(ident)]
+                    build: Option<syn::Ident>,
+                }
+            },
+            syn::parse_quote! {
+                #[parse(builder)]
+                struct Attr {
+                    #[parse(ident)]
+                    r#new: Option<syn::Ident>,
+                }
+            },
+        ] {
+            let res = synthez_core::codegen::parse_attrs::derive(input);
+            assert!(res.is_err(), "should fail, but is ok");
+
+            let err = res.unwrap_err().to_string();
+            assert!(
+                err.ends_with(
+                    "field name is reserved by `#[parse(builder)]` struct \
+                     argument",
+                ),
+                "wrong err:\n{err}",
+            );
+        }
+    }
+
+    #[test]
+    fn constructs_required() {
+        let req = Required::new(syn::Ident::new_on_call_site("req"));
+
+        assert_eq!(*req, syn::Ident::new_on_call_site("req"));
+    }
+}
+
+mod schema {
+    use synthez::{
+        ParseAttrs, Required, Spanning,
+        parse::attrs::schema::{Dedup, Kind},
+        syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Sub {
+        /// Ignores the item.
+        #[parse(ident, dedup = last)]
+        ignore: Option<syn::Ident>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Attr {
+        /// Renames the item.
+        ///
+        /// Multiline.
+        #[parse(value, alias = name)]
+        rename: Required<syn::LitStr>,
+
+        #[parse(value(spaced), arg = with)]
+        paths: Vec<syn::Path>,
+
+        #[parse(map, dedup = first)]
+        ty: std::collections::HashMap<syn::Ident, syn::Type>,
+
+        #[parse(nested)]
+        sub: Option<Spanning<Sub>>,
+
+        #[parse(nested)]
+        recursive: Option<Spanning<Box<Attr>>>,
+    }
+
+    #[test]
+    fn describes_args() {
+        let schema = Attr::schema();
+
+        let names = schema.args.iter().map(|a| a.name).collect::<Vec<_>>();
+        assert_eq!(names, ["rename", "with", "ty", "sub", "recursive"]);
+
+        let rename = &schema.args[0];
+        assert_eq!(rename.aliases, ["name"]);
+        assert_eq!(rename.kind, Kind::Value { spaced: false });
+        assert_eq!(rename.dedup, Dedup::Unique);
+        assert!(rename.required, "should be required");
+        assert_eq!(rename.doc, Some("Renames the item.\n\nMultiline."));
+        assert!(rename.nested.is_none(), "should not be nested");
+
+        let with = &schema.args[1];
+        assert!(with.aliases.is_empty(), "should have no aliases");
+        assert_eq!(with.kind, Kind::Value { spaced: true });
+        assert!(!with.required, "should not be required");
+        assert_eq!(with.doc, None);
+
+        let ty = &schema.args[2];
+        assert_eq!(ty.kind, Kind::Map);
+        assert_eq!(ty.dedup, Dedup::First);
+    }
+
+    #[test]
+    fn describes_nested() {
+        let schema = Attr::schema();
+
+        let sub = &schema.args[3];
+        assert_eq!(sub.kind, Kind::Nested);
+        assert!(sub.nested.is_some(), "should be nested");
+
+        let sub = sub.nested.unwrap()();
+        assert_eq!(sub.args.len(), 1);
+        assert_eq!(sub.args[0].name, "ignore");
+        assert_eq!(sub.args[0].kind, Kind::Ident);
+        assert_eq!(sub.args[0].dedup, Dedup::Last);
+        assert_eq!(sub.args[0].doc, Some("Ignores the item."));
+
+        let recursive = schema.args[4].nested.unwrap()();
+        assert_eq!(recursive.args.len(), schema.args.len());
+    }
+
+    #[test]
+    fn renders_help() {
+        assert_eq!(
+            Attr::schema().help(),
+            "accepted arguments:\n\
+             - `rename = <value>` or `name = <value>` (required): Renames the \
+               item.\n\
+             - `with <value>`\n\
+             - `ty <key> = <value>`\n\
+             - `sub(...)`\n\
+             - `recursive(...)`",
+        );
+    }
+
+    #[test]
+    fn attaches_help_to_unknown_arg() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(rename = "x", unknown)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_err(), "should fail, but ok");
+
+        let errs = res.unwrap_err().into_iter().collect::<Vec<_>>();
+        assert_eq!(errs.len(), 2, "wrong errs: {errs:?}");
+        assert_eq!(errs[0].to_string(), "unknown `unknown` attribute argument");
+        assert_eq!(
+            errs[1].to_string(),
+            format!("help: {}", Attr::schema().help()),
+        );
+    }
+
+    #[test]
+    fn omits_help_on_missing_comma() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(sub(ignore ignore))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_err(), "should fail, but ok");
+
+        let errs = res.unwrap_err().into_iter().collect::<Vec<_>>();
+        assert_eq!(errs.len(), 1, "wrong errs: {errs:?}");
+        assert_eq!(errs[0].to_string(), "expected followed by `,`");
+    }
+}
+
+mod with {
+    use std::collections::BTreeMap;
+
+    use synthez::{
+        IdentExt as _, ParseAttrs, Spanning,
+        syn::{self, parse::ParseStream},
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Sub {
+        #[parse(ident)]
+        ignore: Option<syn::Ident>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Attr {
+        #[parse(ident, args(skip, omit), with = upper)]
+        skip: Option<syn::Ident>,
+
+        #[parse(value, with = lit_str_as_path)]
+        path: Vec<syn::Path>,
+
+        #[parse(value(spaced), with = lit_str_as_path)]
+        spaced: Vec<syn::Path>,
+
+        #[parse(map, with = colon_separated)]
+        ty: BTreeMap<syn::Ident, syn::Type>,
+
+        #[parse(nested, with = sub_from_str)]
+        sub: Option<Spanning<Sub>>,
+    }
+
+    fn upper(input: ParseStream<'_>) -> syn::Result<syn::Ident> {
+        let ident = input.parse::<syn::Ident>()?;
+        Ok(syn::Ident::new(&ident.to_string().to_uppercase(), ident.span()))
+    }
+
+    fn lit_str_as_path(input: ParseStream<'_>) -> syn::Result<syn::Path> {
+        input.parse::<syn::LitStr>()?.parse()
+    }
+
+    fn colon_separated(
+        input: ParseStream<'_>,
+    ) -> syn::Result<(syn::Ident, syn::Type)> {
+        let key = input.parse()?;
+        input.parse::<syn::Token![:]>()?;
+        Ok((key, input.parse()?))
+    }
+
+    fn sub_from_str(input: ParseStream<'_>) -> syn::Result<Sub> {
+        input.parse::<syn::LitStr>()?.parse()
+    }
+
+    #[test]
+    fn parses_ident() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(omit)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().skip,
+            Some(syn::Ident::new_on_call_site("OMIT")),
+        );
+    }
+
+    #[test]
+    fn parses_value() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(path = "foo::Bar", path("baz", "qux::Quux"))]
+            #[attr(spaced "a::B", spaced("c"))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let out = res.unwrap();
+        assert_eq!(
+            out.path,
+            [
+                syn::parse_quote!(foo::Bar),
+                syn::parse_quote!(baz),
+                syn::parse_quote!(qux::Quux),
+            ],
+        );
+        assert_eq!(out.spaced, [syn::parse_quote!(a::B), syn::parse_quote!(c)]);
+    }
+
+    #[test]
+    fn parses_map() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(ty A: u8, ty B: Option<u16>)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let out = res.unwrap();
+        assert_eq!(out.ty.len(), 2);
+        assert_eq!(out.ty[&syn::parse_quote!(A)], syn::parse_quote!(u8));
+        assert_eq!(
+            out.ty[&syn::parse_quote!(B)],
+            syn::parse_quote!(Option<u16>),
+        );
+    }
+
+    #[test]
+    fn parses_nested() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(sub("ignore"))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().sub.unwrap().ignore,
+            Some(syn::Ident::new_on_call_site("ignore")),
+        );
+    }
+
+    #[test]
+    fn propagates_errors() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(path = foo::Bar)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_err(), "should fail, but ok");
+
+        let err = res.unwrap_err().to_string();
+        assert_eq!(err, "expected string literal");
+    }
+}
+
+mod from_str {
+    use synthez::{
+        IdentExt as _, ParseAttrs,
+        syn::{self, parse::ParseStream},
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Attr {
+        #[parse(value(from_str))]
+        path: Vec<syn::Path>,
+
+        #[parse(value(spaced, from_str))]
+        spaced: Vec<syn::Type>,
+
+        #[parse(value(from_str), with = upper)]
+        upper: Option<syn::Ident>,
+    }
+
+    fn upper(input: ParseStream<'_>) -> syn::Result<syn::Ident> {
+        let ident = input.parse::<syn::Ident>()?;
+        Ok(syn::Ident::new(&ident.to_string().to_uppercase(), ident.span()))
+    }
+
+    #[test]
+    fn parses_raw_and_string_values() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(path = foo::Bar, path("baz", qux::Quux))]
+            #[attr(spaced "Option<u8>", spaced(Vec<i32>))]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert_eq!(
+            attr.path,
+            [
+                syn::parse_quote!(foo::Bar),
+                syn::parse_quote!(baz),
+                syn::parse_quote!(qux::Quux),
+            ],
+        );
+        assert_eq!(
+            attr.spaced,
+            [syn::parse_quote!(Option<u8>), syn::parse_quote!(Vec<i32>)],
+        );
+    }
+
+    #[test]
+    fn applies_custom_parser_to_string_contents() {
+        let inputs: [syn::DeriveInput; 2] = [
+            syn::parse_quote! {
+                #[attr(upper = "foo")]
+                struct Dummy;
+            },
+            syn::parse_quote! {
+                #[attr(upper = foo)]
+                struct Dummy;
+            },
+        ];
+
+        for input in inputs {
+            let res = Attr::parse_attrs("attr", &input);
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+            assert_eq!(
+                res.unwrap().upper,
+                Some(syn::Ident::new_on_call_site("FOO")),
+            );
+        }
+    }
+
+    #[test]
+    fn errors_on_invalid_string_contents() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(path = "foo::")]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_err(), "should fail, but succeeded");
+    }
+
+    #[test]
+    fn errors_on_string_values() {
+        for input in [
+            syn::parse_quote! {
+                struct Attr {
+                    #[parse(value(from_str))]
+                    path: Option<syn::LitStr>,
+                }
+            },
+            syn::parse_quote! {
+                struct Attr {
+                    #[parse(value(spaced, from_str))]
+                    path: Vec<Spanning<String>>,
+                }
+            },
+        ] {
+            let res = synthez_core::codegen::parse_attrs::derive(input);
+            assert!(res.is_err(), "should fail, but is ok");
+
+            let err = res.unwrap_err().to_string();
+            assert_eq!(
+                err,
+                "`from_str` value argument cannot be used for string values \
+                 without `with` argument, as they're string literals already",
+            );
+        }
+    }
+}
+
+mod spanning {
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+    use synthez::{
+        ParseAttrs, ParseIdentEnum, Spanning, ToTokens as _, quote::quote, syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Attr {
+        #[parse(value)]
+        lit: HashSet<Spanning<syn::LitStr>>,
+
+        #[parse(value, dedup = first)]
+        ident: BTreeSet<Spanning<syn::Ident>>,
+    }
+
+    #[derive(Debug, Eq, ParseIdentEnum, PartialEq)]
+    enum Mode {
+        Strict,
+        Loose,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(to_tokens)]
+    struct Rendered {
+        #[parse(value)]
+        mode: Option<Spanning<Mode>>,
+
+        #[parse(map)]
+        ty: BTreeMap<Spanning<syn::Ident>, Spanning<syn::Type>>,
+    }
+
+    #[test]
+    fn parses_into_sets() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(lit = "foo", ident(b, a))]
+            #[attr(lit = "bar", ident = b)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert_eq!(attr.lit.len(), 2);
+        assert_eq!(
+            attr.ident.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["a", "b"],
+        );
+    }
+
+    #[test]
+    fn errors_on_duplicates() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(lit = "foo")]
+            #[attr(lit = "foo")]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_err(), "should fail, but succeeded");
+
+        let err = res.unwrap_err().to_string();
+        assert_eq!(err, "duplicated attribute's argument found");
+    }
+
+    #[test]
+    fn renders_respanned_tokens() {
+        let res = syn::parse2::<Spanning<syn::Path>>(quote! { foo::bar });
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let path = res.unwrap();
+        let tokens = path.to_respanned_tokens();
+        assert_eq!(quote! { #tokens }.to_string(), "foo :: bar");
+    }
+
+    #[test]
+    fn renders_as_attribute_args() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(mode = strict, ty A = Option<u8>)]
+            struct Dummy;
+        };
+
+        let res = Rendered::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().to_token_stream().to_string(),
+            quote! { mode = strict, ty A = Option<u8> }.to_string(),
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+mod syn_enums {
+    use synthez::{ParseAttrs, syn};
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct Attr {
+        #[parse(value)]
+        name: Option<syn::LitStr>,
+    }
+
+    #[test]
+    fn parses_from_item() {
+        let input: syn::Item = syn::parse_quote! {
+            #[attr(name = "foo")]
+            fn dummy() {}
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().name.map(|n| n.value()).as_deref(),
+            Some("foo")
+        );
+    }
+
+    #[test]
+    fn parses_from_impl_item() {
+        let input: syn::ImplItem = syn::parse_quote! {
+            #[attr(name = "bar")]
+            const DUMMY: u8 = 0;
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().name.map(|n| n.value()).as_deref(),
+            Some("bar")
+        );
+    }
+
+    #[test]
+    fn parses_from_expr() {
+        let input: syn::Expr = syn::parse_quote! {
+            #[attr(name = "baz")]
+            || {}
+        };
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap().name.map(|n| n.value()).as_deref(),
+            Some("baz")
+        );
+    }
+
+    #[test]
+    fn empty_for_verbatim() {
+        let input = syn::Item::Verbatim(synthez::quote::quote! { #[attr] });
+
+        let res = Attr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(res.unwrap().name, None);
+    }
+}
+
+mod tree {
+    use synthez::{
+        ParseAttrs,
+        parse::attrs::{Inherit, tree},
+        syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct ContainerAttr {
+        #[parse(value)]
+        prefix: Option<syn::LitStr>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct VariantAttr {
+        #[parse(ident)]
+        skip: Option<syn::Ident>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct FieldAttr {
+        #[parse(value)]
+        prefix: Option<syn::LitStr>,
+    }
+
+    impl Inherit<ContainerAttr> for FieldAttr {
+        fn inherit(&mut self, parent: &ContainerAttr) -> syn::Result<()> {
+            if self.prefix.is_none() {
+                self.prefix.clone_from(&parent.prefix);
+            }
+            Ok(())
+        }
+    }
+
+    type Container<'a> =
+        tree::Container<'a, ContainerAttr, VariantAttr, FieldAttr>;
+
+    #[test]
+    fn parses_struct() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(prefix = "foo")]
+            struct Dummy {
+                #[attr(prefix = "bar")]
+                a: u8,
+                b: u8,
+            }
+        };
+
+        let res = Container::parse("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let container = res.unwrap();
+        assert!(container.variants.is_empty());
+        assert_eq!(
+            container
+                .fields
+                .iter()
+                .map(|f| (
+                    f.index,
+                    f.attrs.prefix.as_ref().map(syn::LitStr::value)
+                ))
+                .collect::<Vec<_>>(),
+            [(0, Some("bar".into())), (1, Some("foo".into()))],
+        );
+    }
+
+    #[test]
+    fn parses_enum() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            enum Dummy {
+                #[attr(skip)]
+                A(u8),
+                B { #[attr(prefix = "bar")] b: u8 },
+            }
+        };
+
+        let res = Container::parse("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let container = res.unwrap();
+        assert!(container.fields.is_empty());
+        assert_eq!(container.variants.len(), 2);
+        assert!(container.variants[0].attrs.skip.is_some());
+        assert!(container.variants[1].attrs.skip.is_none());
+        assert_eq!(
+            container
+                .all_fields()
+                .map(|f| f.attrs.prefix.as_ref().map(syn::LitStr::value))
+                .collect::<Vec<_>>(),
+            [None, Some("bar".into())],
+        );
+    }
+
+    #[test]
+    fn combines_errors() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(unknown)]
+            enum Dummy {
+                #[attr(skip, skip)]
+                A(#[attr(prefix = 1)] u8),
+            }
+        };
+
+        let res = Container::parse("attr", &input);
+        assert!(res.is_err(), "should fail, but succeeded");
+
+        let errs = res
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errs,
+            [
+                "unknown `unknown` attribute argument",
+                "help: accepted arguments:\n- `prefix = <value>`",
+                "duplicated attribute's argument found",
+                "expected string literal",
+            ],
+        );
+    }
+}
+
+mod inherit {
+    use synthez::{
+        ParseAttrs, field,
+        parse::attrs::{Inherit as _, tree},
+        syn,
+    };
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct ContainerAttr {
+        #[parse(ident)]
+        skip: Option<syn::Ident>,
+
+        #[parse(value)]
+        rename_all: Option<syn::LitStr>,
+
+        #[parse(value)]
+        bound: Vec<syn::WherePredicate>,
+
+        #[parse(value)]
+        prefix: Option<syn::LitStr>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(inherit = ContainerAttr)]
+    struct FieldAttr {
+        #[parse(ident, inherit)]
+        skip: Option<syn::Ident>,
+
+        #[parse(value, inherit = field::if_empty(
+            |p: &ContainerAttr| Ok(p.rename_all.clone()),
+        ))]
+        rename: Option<syn::LitStr>,
+
+        #[parse(value, inherit)]
+        bound: Vec<syn::WherePredicate>,
+
+        #[parse(value, inherit = |
+            this: &mut Option<syn::LitStr>,
+            p: &ContainerAttr,
+        | -> syn::Result<()> {
+            if this.is_none() {
+                this.clone_from(&p.prefix);
+            }
+            Ok(())
+        })]
+        prefix: Option<syn::LitStr>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(inherit = ContainerAttr)]
+    struct NothingAttr {
+        #[parse(ident)]
+        skip: Option<syn::Ident>,
+    }
+
+    fn container() -> ContainerAttr {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(
+                skip,
+                rename_all = "camelCase",
+                bound(T: Clone),
+                prefix = "my_",
+            )]
+            struct Dummy;
+        };
+        let res = ContainerAttr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        res.unwrap()
+    }
+
+    #[test]
+    fn inherits_when_empty() {
+        let input: syn::Field = syn::parse_quote! { field: u8 };
+
+        let res = FieldAttr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let mut attr = res.unwrap();
+        let res = attr.inherit(&container());
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert!(attr.skip.is_some());
+        assert_eq!(attr.rename, Some(syn::parse_quote!("camelCase")));
+        assert_eq!(attr.bound.len(), 1);
+        assert_eq!(attr.prefix, Some(syn::parse_quote!("my_")));
+    }
+
+    #[test]
+    fn keeps_specified() {
+        let input: syn::Field = syn::parse_quote! {
+            #[attr(rename = "foo", bound(U: Copy, V: Copy), prefix = "x_")]
+            field: u8
+        };
+
+        let res = FieldAttr::parse_attrs("attr", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let mut attr = res.unwrap();
+        let res = attr.inherit(&container());
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert!(attr.skip.is_some());
+        assert_eq!(attr.rename, Some(syn::parse_quote!("foo")));
+        assert_eq!(attr.bound.len(), 2);
+        assert_eq!(attr.prefix, Some(syn::parse_quote!("x_")));
+    }
+
+    #[test]
+    fn inherits_nothing_by_default() {
+        let mut attr = NothingAttr::default();
+        let res = attr.inherit(&container());
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert!(attr.skip.is_none());
+    }
+
+    #[test]
+    fn works_with_tree() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[attr(rename_all = "snake_case")]
+            struct Dummy {
+                a: u8,
+                #[attr(rename = "b")]
+                b: u8,
+            }
+        };
+
+        let res =
+            tree::Container::<ContainerAttr, NothingAttr, FieldAttr>::parse(
+                "attr", &input,
+            );
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(
+            res.unwrap()
+                .fields
+                .iter()
+                .map(|f| f.attrs.rename.as_ref().map(syn::LitStr::value))
+                .collect::<Vec<_>>(),
+            [Some("snake_case".into()), Some("b".into())],
+        );
+    }
+}
+
+mod docs {
+    use synthez::{parse::attr::Docs, syn};
+
+    fn docs(input: &syn::DeriveInput) -> Docs {
+        let res = Docs::from_attrs(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        res.unwrap()
+    }
+
+    #[test]
+    fn splits_summary_and_body() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            ///
+            /// Summary.
+            ///
+            /// First paragraph
+            /// of body.
+            ///
+            /// Second paragraph.
+            ///
+            struct Dummy;
+        };
+        let docs = docs(&input);
+
+        assert_eq!(
+            docs.summary().as_deref().map(String::as_str),
+            Some("Summary.")
+        );
+        assert_eq!(
+            docs.body().as_deref().map(String::as_str),
+            Some("First paragraph\nof body.\n\nSecond paragraph."),
+        );
+        assert_eq!(docs.lines().len(), 8);
+    }
+
+    #[test]
+    fn has_no_summary_when_starts_with_heading() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            /// # Title
+            ///
+            /// Text.
+            struct Dummy;
+        };
+        let docs = docs(&input);
+
+        assert!(docs.summary().is_none());
+        assert_eq!(
+            docs.body().as_deref().map(String::as_str),
+            Some("# Title\n\nText."),
+        );
+    }
+
+    #[test]
+    fn ignores_headings_inside_code_blocks() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            /// Summary.
+            ///
+            /// ## Examples
+            ///
+            /// ~~~
+            /// # hidden();
+            ///     indented();
+            /// ~~~
+            ///
+            ///```text
+            /// unclosed
+            struct Dummy;
+        };
+        let docs = docs(&input);
+
+        let headings = docs.headings();
+        assert_eq!(headings.len(), 1);
+        assert_eq!(headings[0].level, 2);
+        assert_eq!(headings[0].title.as_str(), "Examples");
+
+        let code = docs.code_blocks();
+        assert_eq!(code.len(), 2);
+        assert_eq!(code[0].lang, None);
+        assert_eq!(code[0].code.as_str(), "# hidden();\n    indented();");
+        assert_eq!(code[1].lang.as_deref(), Some("text"));
+        assert_eq!(code[1].code.as_str(), "unclosed");
+    }
+
+    #[test]
+    fn splits_block_comments() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[doc = "Summary.\n\nBody."]
+            struct Dummy;
+        };
+        let docs = docs(&input);
+
+        assert_eq!(
+            docs.summary().as_deref().map(String::as_str),
+            Some("Summary.")
+        );
+        assert_eq!(docs.body().as_deref().map(String::as_str), Some("Body."));
+    }
+
+    #[test]
+    fn collects_unresolved_values() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            /// Summary.
+            #[doc = include_str!("README.md")]
+            #[doc(hidden)]
+            struct Dummy;
+        };
+        let docs = docs(&input);
+
+        assert_eq!(
+            docs.text().as_deref().map(String::as_str),
+            Some("Summary.")
+        );
+        assert_eq!(docs.unresolved().len(), 1);
+        assert!(!docs.is_empty());
+    }
+
+    #[test]
+    fn errors_on_non_string_literal() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[doc = 1]
+            struct Dummy;
+        };
+
+        assert!(Docs::from_attrs(&input.attrs).is_err(), "should fail");
+    }
+}
+
+mod std_attrs {
+    use synthez::{parse::attr, syn};
+
+    #[test]
+    fn parses_deprecated_forms() {
+        for (input, since, note) in [
+            (syn::parse_quote! { #[deprecated] struct Dummy; }, None, None),
+            (
+                syn::parse_quote! { #[deprecated = "foo"] struct Dummy; },
+                None,
+                Some("foo"),
+            ),
+            (
+                syn::parse_quote! {
+                    #[deprecated(note = "bar", since = "0.1.0")]
+                    struct Dummy;
+                },
+                Some("0.1.0"),
+                Some("bar"),
+            ),
+        ] {
+            let input: syn::DeriveInput = input;
+            let res = attr::deprecated(&input.attrs);
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+            let deprecated = res.unwrap();
+            assert!(deprecated.is_some(), "`#[deprecated]` is absent");
+            let deprecated = deprecated.unwrap();
+            assert_eq!(
+                deprecated.since.as_ref().map(syn::LitStr::value).as_deref(),
+                since
+            );
+            assert_eq!(
+                deprecated.note.as_ref().map(syn::LitStr::value).as_deref(),
+                note
+            );
+        }
+    }
+
+    #[test]
+    fn errors_on_malformed_deprecated() {
+        for input in [
+            syn::parse_quote! { #[deprecated(reason = "foo")] struct Dummy; },
+            syn::parse_quote! {
+                #[deprecated(note = "foo", note = "bar")]
+                struct Dummy;
+            },
+            syn::parse_quote! {
+                #[deprecated]
+                #[deprecated]
+                struct Dummy;
+            },
+        ] {
+            let input: syn::DeriveInput = input;
+            assert!(attr::deprecated(&input.attrs).is_err(), "should fail");
+        }
+    }
+
+    #[test]
+    fn parses_must_use() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[must_use = "foo"]
+            struct Dummy;
+        };
+
+        let res = attr::must_use(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let must_use = res.unwrap();
+        assert_eq!(
+            must_use.and_then(|m| m.into_inner().note).map(|n| n.value()),
+            Some("foo".into()),
+        );
+
+        let input: syn::DeriveInput = syn::parse_quote! { struct Dummy; };
+        let res = attr::must_use(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        assert!(res.unwrap().is_none(), "`#[must_use]` is present");
+    }
+
+    #[test]
+    fn parses_repr() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[repr(transparent)]
+            #[repr(packed(2), i64)]
+            struct Dummy;
+        };
+
+        let res = attr::repr(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let repr = res.unwrap();
+        assert!(repr.transparent.is_some());
+        assert_eq!(repr.int.map(|i| i.to_string()).as_deref(), Some("i64"));
+        assert_eq!(
+            repr.packed
+                .and_then(|p| p.into_inner())
+                .map(|n| n.base10_parse::<u32>().unwrap()),
+            Some(2),
+        );
+        assert!(repr.c.is_none());
+        assert!(repr.rust.is_none());
+    }
+
+    #[test]
+    fn parses_rust_repr() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[repr(Rust, u8)]
+            enum Dummy {}
+        };
+
+        let res = attr::repr(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let repr = res.unwrap();
+        assert!(repr.rust.is_some());
+        assert_eq!(repr.int.map(|i| i.to_string()).as_deref(), Some("u8"));
+        assert!(repr.c.is_none());
+    }
+
+    #[test]
+    fn errors_on_wrong_repr() {
+        for input in [
+            syn::parse_quote! { #[repr(u8, u16)] enum Dummy {} },
+            syn::parse_quote! { #[repr(C)] #[repr(C)] struct Dummy; },
+            syn::parse_quote! { #[repr(Rust)] #[repr(Rust)] struct Dummy; },
+            syn::parse_quote! { #[repr(unknown)] struct Dummy; },
+        ] {
+            let input: syn::DeriveInput = input;
+            assert!(attr::repr(&input.attrs).is_err(), "should fail");
+        }
+    }
+
+    #[test]
+    fn parses_derives() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[derive(Clone, Copy,)]
+            #[derive(std::fmt::Debug)]
+            struct Dummy;
+        };
+
+        let res = attr::derives(&input.attrs);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let derives = res.unwrap();
+        assert_eq!(derives.len(), 3);
+        assert!(derives[0].is_ident("Clone"));
+        assert!(derives[1].is_ident("Copy"));
+        assert_eq!(derives[2].segments.len(), 3);
+    }
+}
+
+mod lenient {
+    use synthez::{ParseAttrs, syn};
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(lenient)]
+    struct Attr {
+        #[parse(value)]
+        rename: Option<syn::LitStr>,
+
+        #[parse(ident)]
+        skip: Option<syn::Ident>,
+
+        #[parse(ident)]
+        default: Option<syn::Ident>,
+    }
+
+    #[test]
+    fn skips_unknown_args() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[serde(tag = "t", content = "c", rename_all = "camelCase")]
+            #[serde(bound(serialize = "T: Serialize", deserialize = ""))]
+            #[serde(with = ::some::module, untagged, 42, "str" = 1)]
+            #[serde(skip)]
+            #[serde(from = Foo<A, B>, rename = "foo")]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("serde", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert_eq!(attr.rename.map(|r| r.value()).as_deref(), Some("foo"));
+        assert!(attr.skip.is_some());
+    }
+
+    #[test]
+    fn parses_known_args_strictly() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[serde(rename = "foo", unknown, rename = "bar")]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("serde", &input);
+        assert!(res.is_err(), "should fail, but succeeded");
+
+        let err = res.unwrap_err().to_string();
+        assert_eq!(err, "duplicated attribute's argument found");
+    }
+
+    #[test]
+    fn skips_known_args_of_another_shape() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            #[serde(rename(serialize = "a", deserialize = "b"))]
+            #[serde(default = "Foo::new", skip)]
+            struct Dummy;
+        };
+
+        let res = Attr::parse_attrs("serde", &input);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert!(attr.rename.is_none());
+        assert!(attr.default.is_none());
+        assert!(attr.skip.is_some());
+    }
+}
+
+mod meta {
+    use synthez::{ParseAttrs, Required, quote::ToTokens as _, syn};
+
+    #[derive(Debug, Default, ParseAttrs)]
+    #[parse(to_tokens)]
+    struct Attr {
+        #[parse(ident)]
+        skip: Option<syn::Ident>,
+
+        #[parse(value)]
+        rename: Option<syn::LitStr>,
+    }
+
+    #[derive(Debug, Default, ParseAttrs)]
+    struct RequiredAttr {
+        #[parse(value)]
+        name: Required<syn::LitStr>,
+    }
+
+    #[test]
+    fn parses_from_meta() {
+        let attr: syn::Attribute = syn::parse_quote! {
+            #[attr(skip, rename = "foo")]
+        };
+
+        let res = Attr::from_meta(&attr.meta);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert!(attr.skip.is_some());
+        assert_eq!(attr.rename.map(|r| r.value()).as_deref(), Some("foo"));
+    }
+
+    #[test]
+    fn parses_from_path_meta() {
+        let attr: syn::Attribute = syn::parse_quote! { #[attr] };
+
+        let res = Attr::from_meta(&attr.meta);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let attr = res.unwrap();
+        assert!(attr.skip.is_none());
+        assert!(attr.rename.is_none());
+    }
+
+    #[test]
+    fn errors_on_name_value_meta() {
+        let attr: syn::Attribute = syn::parse_quote! { #[attr = "foo"] };
+
+        let res = Attr::from_meta(&attr.meta);
+        assert!(res.is_err(), "should fail, but succeeded");
+    }
+
+    #[test]
+    fn validates_from_meta() {
+        let attr: syn::Attribute = syn::parse_quote! { #[my::attr()] };
+
+        let res = RequiredAttr::from_meta(&attr.meta);
+        assert!(res.is_err(), "should fail, but succeeded");
+
+        let err = res.unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "`name` argument of `#[my::attr]` attribute is expected to be \
+             present, but is absent",
+        );
+    }
+
+    #[test]
+    fn parses_from_nested_meta() {
+        let attr: syn::Attribute = syn::parse_quote! {
+            #[outer(first(rename = "foo"), second, other = 1)]
+        };
+
+        let mut parsed = vec![];
+        let res = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("other") {
+                _ = meta.value()?.parse::<syn::LitInt>()?;
+            } else {
+                parsed.push(Attr::from_nested_meta(&meta)?);
+            }
+            Ok(())
+        });
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(
+            parsed[0].rename.as_ref().map(syn::LitStr::value).as_deref(),
+            Some("foo"),
+        );
+        assert!(parsed[1].rename.is_none());
+    }
+
+    #[test]
+    fn renders_meta() {
+        let attr: syn::Attribute = syn::parse_quote! {
+            #[attr(skip, rename = "foo")]
+        };
+        let res = Attr::from_meta(&attr.meta);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        let attr = res.unwrap();
+
+        let meta = attr.to_meta("other");
+        assert!(meta.path().is_ident("other"));
+        let res = Attr::from_meta(&meta);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        assert_eq!(
+            res.unwrap().rename.map(|r| r.value()).as_deref(),
+            Some("foo"),
+        );
+
+        let res = attr.to_nested_metas();
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        let metas = res.unwrap();
+        assert_eq!(
+            metas
+                .iter()
+                .map(|m| m.to_token_stream().to_string())
+                .collect::<Vec<_>>(),
+            ["skip", "rename = \"foo\""],
+        );
+        assert!(matches!(metas[0], syn::Meta::Path(_)));
+        assert!(matches!(metas[1], syn::Meta::NameValue(_)));
+    }
+
+    #[test]
+    fn renders_parsable_by_nested_meta() {
+        let attr: syn::Attribute = syn::parse_quote! {
+            #[attr(skip, rename = "foo")]
+        };
+        let res = Attr::from_meta(&attr.meta);
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        let attr = res.unwrap().to_attribute("other");
+
+        let mut rename = None;
+        let res = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            }
+            Ok(())
+        });
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+        assert_eq!(rename.as_deref(), Some("foo"));
+    }
+}
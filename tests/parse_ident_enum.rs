@@ -0,0 +1,166 @@
+#![forbid(non_ascii_idents, unsafe_code)]
+
+use synthez::{ParseIdentEnum, Spanning, quote::quote, syn};
+
+#[derive(Clone, Copy, Debug, Eq, ParseIdentEnum, PartialEq)]
+enum Mode {
+    Strict,
+    LooseChecks,
+    #[ident_enum(rename = type)]
+    Ty,
+}
+
+#[derive(Clone, Copy, Debug, Eq, ParseIdentEnum, PartialEq)]
+#[ident_enum(rename_all = "kebab-case", from_str)]
+enum Style {
+    UpperCamel,
+    #[ident_enum(rename = "lower")]
+    LowerCamel,
+}
+
+mod parse {
+    use super::*;
+
+    #[test]
+    fn parses_converted_names() {
+        for (input, expected) in [
+            (quote! { strict }, Mode::Strict),
+            (quote! { loose_checks }, Mode::LooseChecks),
+            (quote! { type }, Mode::Ty),
+            (quote! { r#type }, Mode::Ty),
+        ] {
+            let res = syn::parse2::<Mode>(input);
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+            assert_eq!(res.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn parses_string_literals() {
+        for (input, expected) in [
+            (quote! { "upper-camel" }, Style::UpperCamel),
+            (quote! { "lower" }, Style::LowerCamel),
+            (quote! { lower }, Style::LowerCamel),
+        ] {
+            let res = syn::parse2::<Style>(input);
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+            assert_eq!(res.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn parses_spanned() {
+        let res = syn::parse::Parser::parse2(
+            <Mode as synthez::ParseIdentEnum>::parse_spanned,
+            quote! { strict },
+        );
+        assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+
+        let mode: Spanning<Mode> = res.unwrap();
+        assert_eq!(*mode, Mode::Strict);
+    }
+
+    #[test]
+    fn errors_on_unknown_value() {
+        let res = syn::parse2::<Mode>(quote! { lenient });
+        assert!(res.is_err(), "should fail, but succeeded");
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "unknown value `lenient`, expected one of: `strict`, \
+             `loose_checks`, `type`",
+        );
+    }
+
+    #[test]
+    fn errors_on_string_literal_without_from_str() {
+        let res = syn::parse2::<Mode>(quote! { "strict" });
+        assert!(res.is_err(), "should fail, but succeeded");
+    }
+}
+
+mod values {
+    use synthez::ParseIdentEnum as _;
+
+    use super::*;
+
+    #[test]
+    fn lists_accepted_values() {
+        assert_eq!(Mode::VALUES, ["strict", "loose_checks", "type"]);
+        assert_eq!(Style::VALUES, ["upper-camel", "lower"]);
+    }
+
+    #[test]
+    fn displays_values() {
+        assert_eq!(Mode::LooseChecks.to_string(), "loose_checks");
+        assert_eq!(Style::UpperCamel.to_string(), "upper-camel");
+    }
+}
+
+mod to_tokens {
+    use super::*;
+
+    #[test]
+    fn renders_idents() {
+        let (strict, ty) = (Mode::Strict, Mode::Ty);
+
+        assert_eq!(quote! { #strict #ty }.to_string(), "strict type");
+    }
+
+    #[test]
+    fn renders_non_idents_as_string_literals() {
+        let style = Style::UpperCamel;
+
+        assert_eq!(quote! { #style }.to_string(), "\"upper-camel\"");
+    }
+
+    #[test]
+    fn round_trips() {
+        for mode in [Mode::Strict, Mode::LooseChecks, Mode::Ty] {
+            let res = syn::parse2::<Mode>(quote! { #mode });
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+            assert_eq!(res.unwrap(), mode);
+        }
+        for style in [Style::UpperCamel, Style::LowerCamel] {
+            let res = syn::parse2::<Style>(quote! { #style });
+            assert!(res.is_ok(), "failed: {}", res.unwrap_err());
+            assert_eq!(res.unwrap(), style);
+        }
+    }
+}
+
+mod derive {
+    use synthez_core::codegen::parse_ident_enum;
+
+    use super::*;
+
+    #[test]
+    fn errors_on_non_ident_value_without_from_str() {
+        for input in [
+            syn::parse_quote! {
+                #[ident_enum(rename_all = "kebab-case")]
+                enum Style {
+                    UpperCamel,
+                }
+            },
+            syn::parse_quote! {
+                enum Style {
+                    #[ident_enum(rename = "foo-bar")]
+                    UpperCamel,
+                }
+            },
+        ] {
+            let res = parse_ident_enum::derive(&input);
+            assert!(res.is_err(), "should fail, but succeeded");
+
+            let err = res.unwrap_err().to_string();
+            assert!(
+                err.ends_with(
+                    "value is not a valid identifier, so requires \
+                     `#[ident_enum(from_str)]` enum argument",
+                ),
+                "wrong error: {err}",
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+#![forbid(non_ascii_idents, unsafe_code)]
+
+use synthez::{
+    proc_macro2::{LineColumn, Span, TokenStream, TokenTree},
+    spanned::{self, IntoSpan as _},
+    syn,
+};
+
+/// Parses the given `input` as [`TokenTree`]s, so each of them has its own
+/// location.
+fn token_trees(input: &str) -> Vec<TokenTree> {
+    input.parse::<TokenStream>().unwrap().into_iter().collect()
+}
+
+/// Returns the start and end locations of the given [`Span`].
+fn location(span: Span) -> (LineColumn, LineColumn) {
+    (span.start(), span.end())
+}
+
+/// Returns the [`Span`]s of all the [`TokenTree`]s in the given
+/// [`TokenStream`], descending into groups.
+fn all_spans(tokens: TokenStream) -> Vec<Span> {
+    tokens
+        .into_iter()
+        .flat_map(|tt| {
+            let mut spans = vec![tt.span()];
+            if let TokenTree::Group(g) = tt {
+                spans.extend(all_spans(g.stream()));
+            }
+            spans
+        })
+        .collect()
+}
+
+#[test]
+fn respan_relocates_all_tokens() {
+    let mut tts = token_trees("foo(bar, [baz]) target");
+    let target = tts.pop().unwrap().span();
+    let tokens = tts.into_iter().collect::<TokenStream>();
+    assert!(
+        all_spans(tokens.clone())
+            .into_iter()
+            .all(|s| location(s) != location(target)),
+    );
+
+    let respanned = spanned::respan(tokens, target);
+
+    assert_eq!(respanned.to_string(), "foo (bar , [baz])");
+    let spans = all_spans(respanned);
+    assert_eq!(spans.len(), 6);
+    assert!(spans.into_iter().all(|s| location(s) == location(target)));
+}
+
+#[test]
+fn error_into_span() {
+    let tts = token_trees("foo bar");
+    let err = syn::Error::new(tts[1].span(), "msg");
+
+    let span = err.into_span();
+
+    assert_eq!(location(span), location(tts[1].span()));
+    assert_eq!(span.start().column, 4);
+    assert_eq!(span.end().column, 7);
+}
+
+#[test]
+fn token_tree_into_span() {
+    let tts = token_trees("foo (bar)");
+
+    let span = tts[1].clone().into_span();
+
+    assert_eq!(location(span), location(tts[1].span()));
+    assert_eq!(span.start().column, 4);
+    assert_eq!(span.end().column, 9);
+}
+
+#[test]
+fn some_into_span() {
+    let ident: syn::Ident = syn::parse_str("foo").unwrap();
+
+    let span = Some(&ident).into_span();
+
+    assert_eq!(location(span), location(ident.span()));
+    assert_eq!(span.end().column, 3);
+}
+
+#[test]
+fn none_into_call_site_span() {
+    let span = None::<&syn::Ident>.into_span();
+
+    assert_eq!(location(span), location(Span::call_site()));
+    assert_eq!(format!("{span:?}"), format!("{:?}", Span::call_site()));
+}
@@ -1,6 +1,6 @@
 #![forbid(non_ascii_idents, unsafe_code)]
 
-use synthez::{ToTokens, proc_macro2::TokenStream, quote::quote};
+use synthez::{ToTokens, proc_macro2::TokenStream, quote::quote, syn};
 
 #[derive(ToTokens)]
 #[to_tokens(append(impl_tokens))]
@@ -28,3 +28,93 @@ fn appends_tokens() {
 
     assert_eq!(code.to_string(), "whoopsie daisy");
 }
+
+#[derive(ToTokens)]
+enum Expr {
+    Ident(syn::Ident),
+    Binary {
+        left: syn::Ident,
+        op: syn::BinOp,
+        right: syn::Ident,
+    },
+    #[to_tokens(fields(1, 0))]
+    Swapped(syn::Ident, syn::Ident),
+    #[to_tokens(fields(out), append(suffix_tokens))]
+    Suffixed {
+        out: syn::Ident,
+        #[expect(dead_code, reason = "not emitted")]
+        ignored: u8,
+    },
+    #[to_tokens(append(unit_tokens))]
+    Unit,
+    Empty,
+}
+
+impl Expr {
+    fn suffix_tokens(&self) -> TokenStream {
+        quote! { suffix }
+    }
+
+    fn unit_tokens(&self) -> TokenStream {
+        quote! { unit }
+    }
+}
+
+#[derive(ToTokens)]
+#[to_tokens(append(impl_tokens))]
+enum Appended {
+    #[expect(dead_code, reason = "not emitted")]
+    Foo(u8),
+}
+
+impl Appended {
+    fn impl_tokens(&self) -> TokenStream {
+        quote! { appended }
+    }
+}
+
+#[derive(ToTokens)]
+enum Never {}
+
+#[test]
+fn emits_enum_variant_fields() {
+    let ident = Expr::Ident(syn::parse_quote! { foo });
+    let binary = Expr::Binary {
+        left: syn::parse_quote! { a },
+        op: syn::parse_quote! { + },
+        right: syn::parse_quote! { b },
+    };
+    let swapped = Expr::Swapped(
+        syn::parse_quote! { first },
+        syn::parse_quote! { second },
+    );
+
+    assert_eq!(quote! { #ident }.to_string(), "foo");
+    assert_eq!(quote! { #binary }.to_string(), "a + b");
+    assert_eq!(quote! { #swapped }.to_string(), "second first");
+}
+
+#[test]
+fn appends_enum_variant_tokens() {
+    let suffixed =
+        Expr::Suffixed { out: syn::parse_quote! { bar }, ignored: 0 };
+    let (unit, empty) = (Expr::Unit, Expr::Empty);
+
+    assert_eq!(quote! { #suffixed }.to_string(), "bar suffix");
+    assert_eq!(quote! { #unit }.to_string(), "unit");
+    assert_eq!(quote! { #empty }.to_string(), "");
+}
+
+#[test]
+fn appends_enum_tokens() {
+    let appended = Appended::Foo(1);
+
+    assert_eq!(quote! { #appended }.to_string(), "appended");
+}
+
+#[test]
+fn implements_for_empty_enum() {
+    fn assert_to_tokens<T: ToTokens>() {}
+
+    assert_to_tokens::<Never>();
+}